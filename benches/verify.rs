@@ -0,0 +1,98 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use validex::*;
+
+/// An expensive rule, passing when the digest of the input is at most `.0`.
+struct Digest(u64);
+
+/// Same as [`Digest`], relying on the default two-pass `try_verify`.
+struct TwoPass(u64);
+
+fn digest(s: &str) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+    for _ in 0..64 {
+        for byte in s.bytes() {
+            hash = (hash ^ byte as u64).wrapping_mul(0x100_0000_01b3);
+        }
+    }
+    hash
+}
+
+#[derive(Debug)]
+struct DigestError(u64);
+
+impl Error for DigestError {}
+impl errors::Params for DigestError {}
+impl Display for DigestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "digest {:x} is too large", self.0)
+    }
+}
+
+impl Verify<&String> for Digest {
+    type Error = DigestError;
+    fn verify(&self, val: &String) -> bool {
+        digest(val) <= self.0
+    }
+    fn error(&self, val: &String) -> Self::Error {
+        DigestError(digest(val))
+    }
+    fn try_verify(&self, val: &String) -> Result<(), Self::Error> {
+        let digest = digest(val);
+        if digest > self.0 {
+            return Err(DigestError(digest));
+        }
+        Ok(())
+    }
+}
+
+impl Verify<&String> for TwoPass {
+    type Error = DigestError;
+    fn verify(&self, val: &String) -> bool {
+        digest(val) <= self.0
+    }
+    fn error(&self, val: &String) -> Self::Error {
+        DigestError(digest(val))
+    }
+}
+
+fn bench(c: &mut Criterion) {
+    let input = "the quick brown fox jumps over the lazy dog".repeat(4);
+
+    let mut group = c.benchmark_group("failing");
+    group.bench_function("two-pass", |b| {
+        let rule = All((Length(1..), TwoPass(0)));
+        b.iter(|| rule.check(black_box(&input)).is_err())
+    });
+    group.bench_function("single-pass", |b| {
+        let rule = All((Length(1..), Digest(0)));
+        b.iter(|| rule.check(black_box(&input)).is_err())
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("any");
+    group.bench_function("boxed", |b| {
+        let rule = Any((Length(..4), Not(Ascii), Contains("@")));
+        b.iter(|| rule.check(black_box(&input)).is_err())
+    });
+    group.bench_function("static", |b| {
+        let rule = AnyOf((Length(..4), Not(Ascii), Contains("@")));
+        b.iter(|| rule.check(black_box(&input)).is_err())
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("passing");
+    group.bench_function("two-pass", |b| {
+        let rule = All((Length(1..), TwoPass(u64::MAX)));
+        b.iter(|| rule.check(black_box(&input)).is_ok())
+    });
+    group.bench_function("single-pass", |b| {
+        let rule = All((Length(1..), Digest(u64::MAX)));
+        b.iter(|| rule.check(black_box(&input)).is_ok())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -1,51 +1,449 @@
-use quote2::proc_macro2::{TokenStream, TokenTree};
-use quote2::{Quote, quote};
+use crate::typed;
+use quote2::proc_macro2::{Spacing, Span, TokenStream, TokenTree};
+use quote2::{Quote, ToTokens, quote};
 use syn::*;
 
 pub fn expand(input: &DeriveInput) -> TokenStream {
     let DeriveInput {
-        ident,
-        generics,
-        data,
-        ..
+        ident, generics, ..
     } = input;
 
-    let body = quote(|t| match data {
+    if let Err(err) = verify_attrs(input) {
+        return err.to_compile_error();
+    }
+    let typed = match get_error_type(&input.attrs) {
+        Ok(Some(error)) => typed::expand(input, &error),
+        _ => TokenStream::new(),
+    };
+
+    let check = body(input, false);
+    let check_all = body(input, true);
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut t = TokenStream::new();
+    quote!(t, {
+        impl #impl_generics ::validex::Validate for #ident #ty_generics #where_clause {
+            type Error<'e> = ::validex::errors::FieldError<'e> where Self: 'e;
+            type Errors<'e> = ::validex::errors::FieldErrors<'e> where Self: 'e;
+
+            fn check(&self) -> ::std::result::Result<(), Self::Error<'_>> {
+                #check
+                ::std::result::Result::Ok(())
+            }
+
+            fn check_all(&self) -> ::std::result::Result<(), Self::Errors<'_>> {
+                let mut __errors = ::std::vec::Vec::new();
+                #check_all
+                if __errors.is_empty() {
+                    ::std::result::Result::Ok(())
+                } else {
+                    ::std::result::Result::Err(::validex::errors::FieldErrors(__errors))
+                }
+            }
+        }
+        #typed
+    });
+    t
+}
+
+fn body(input: &DeriveInput, all: bool) -> impl ToTokens + '_ {
+    let DeriveInput { attrs, data, .. } = input;
+    quote(move |t| match data {
         Data::Struct(DataStruct { fields, .. }) => {
-            for field in fields {
-                if let (Some(input), Some(key)) = (get_validex_field(field), &field.ident) {
-                    let name = key.to_string();
-                    split_comma(input, |input| {
-                        quote!(t, { ::validex::__field(#name, &#input, &self.#key)?; });
+            let newtype = is_newtype(fields);
+            for (idx, field) in fields.iter().enumerate() {
+                let member = member(idx, field);
+                let path = quote(|t| {
+                    let segment = segment(idx, field);
+                    quote!(t, { &[#segment] });
+                });
+                let value = quote(|t| {
+                    quote!(t, { &self.#member });
+                });
+                if newtype && let Some(input) = get_container_rules(attrs) {
+                    let path = quote(|t| {
+                        quote!(t, { &[] });
+                    });
+                    rules(t, all, &input, &path, &value);
+                }
+                if let Some(input) = get_validex_field(field) {
+                    rules(t, all, input, &path, &value);
+                }
+            }
+            if !newtype {
+                container_rules(t, all, attrs);
+            }
+        }
+        Data::Enum(DataEnum { variants, .. }) => {
+            let arms = quote(|t| {
+                for Variant {
+                    ident: variant,
+                    fields,
+                    ..
+                } in variants
+                {
+                    let name = variant.to_string();
+                    let bindings = quote(|t| {
+                        for (idx, field) in fields.iter().enumerate() {
+                            if get_validex_field(field).is_some() {
+                                let member = member(idx, field);
+                                let binding = binding(idx);
+                                quote!(t, { #member: #binding, });
+                            }
+                        }
+                    });
+                    let checks = quote(|t| {
+                        for (idx, field) in fields.iter().enumerate() {
+                            if let Some(input) = get_validex_field(field) {
+                                let path = quote(|t| {
+                                    let segment = segment(idx, field);
+                                    quote!(t, {
+                                        &[::validex::errors::Segment::Variant(#name), #segment]
+                                    });
+                                });
+                                let binding = binding(idx);
+                                rules(t, all, input, &path, &binding);
+                            }
+                        }
+                    });
+                    quote!(t, {
+                        Self::#variant { #bindings .. } => { #checks }
                     });
                 }
+            });
+            if variants.is_empty() {
+                quote!(t, { match *self {} });
+            } else {
+                quote!(t, { match self { #arms } });
+            }
+            container_rules(t, all, attrs);
+        }
+        Data::Union(data) => t.extend(union_error(data).to_compile_error()),
+    })
+}
+
+pub fn union_error(data: &DataUnion) -> Error {
+    Error::new_spanned(data.union_token, "`Check` cannot be derived for unions")
+}
+
+pub struct Rule {
+    pub expr: TokenStream,
+    pub field: Option<Member>,
+    pub code: Option<LitStr>,
+    pub message: Option<LitStr>,
+}
+
+pub fn parse_rules(tokens: &TokenStream) -> Result<Vec<Rule>> {
+    let mut rules: Vec<Rule> = Vec::new();
+    let mut error = None;
+    split_comma(tokens, |tokens| {
+        let Some((name, value)) = parse_option(&tokens) else {
+            return rules.push(Rule {
+                expr: tokens,
+                field: None,
+                code: None,
+                message: None,
+            });
+        };
+        let result = match rules.last_mut() {
+            None => Err(Error::new(
+                name.span(),
+                "expected a rule before this option",
+            )),
+            Some(rule) if is_nested(&rule.expr) => Err(Error::new(
+                name.span(),
+                "options are not supported on `nested`",
+            )),
+            Some(rule) if name == "field" => parse2(value).map(|field| rule.field = Some(field)),
+            Some(rule) if name == "code" => parse2(value).map(|code| rule.code = Some(code)),
+            Some(rule) if name == "message" => {
+                parse2(value).map(|message| rule.message = Some(message))
             }
+            Some(_) => Err(Error::new(name.span(), format!("unknown option `{name}`"))),
+        };
+        if let Err(err) = result {
+            error.get_or_insert(err);
         }
-        Data::Enum(_) => unimplemented!(),
-        Data::Union(_) => unimplemented!(),
     });
+    match error {
+        Some(err) => Err(err),
+        None => Ok(rules),
+    }
+}
 
-    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-    let mut t = TokenStream::new();
-    quote!(t, {
-        impl #impl_generics #ident #ty_generics #where_clause {
-            fn check(&self) -> ::std::result::Result<(), ::validex::errors::FieldError<'_>> {
-                #body
-                ::std::result::Result::Ok(())
+fn parse_option(tokens: &TokenStream) -> Option<(Ident, TokenStream)> {
+    let mut tokens = tokens.clone().into_iter();
+    match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Ident(name)), Some(TokenTree::Punct(eq)))
+            if eq.as_char() == '=' && eq.spacing() == Spacing::Alone =>
+        {
+            Some((name, tokens.collect()))
+        }
+        _ => None,
+    }
+}
+
+fn verify_attrs(input: &DeriveInput) -> Result<()> {
+    let (fields, newtype): (Vec<&Field>, _) = match &input.data {
+        Data::Struct(data) => (data.fields.iter().collect(), is_newtype(&data.fields)),
+        Data::Enum(data) => (
+            data.variants.iter().flat_map(|v| &v.fields).collect(),
+            false,
+        ),
+        Data::Union(data) => return Err(union_error(data)),
+    };
+    for field in &fields {
+        if let Some(tokens) = get_validex_field(field) {
+            no_field_option(tokens)?;
+        }
+    }
+    get_error_type(&input.attrs)?;
+    if let Some(tokens) = get_container_rules(&input.attrs) {
+        if newtype {
+            no_field_option(&tokens)?;
+        }
+        let members = match &input.data {
+            Data::Struct(data) => members(&data.fields),
+            Data::Enum(data) => data
+                .variants
+                .iter()
+                .flat_map(|v| members(&v.fields))
+                .collect(),
+            Data::Union(_) => Vec::new(),
+        };
+        for rule in parse_rules(&tokens)? {
+            if is_nested(&rule.expr) {
+                return Err(Error::new_spanned(
+                    &rule.expr,
+                    "`nested` is only supported on fields",
+                ));
+            }
+            if let Some(field) = rule.field
+                && !members.contains(&field)
+            {
+                let name = match &field {
+                    Member::Named(ident) => ident.to_string(),
+                    Member::Unnamed(index) => index.index.to_string(),
+                };
+                return Err(Error::new_spanned(
+                    field,
+                    format!("no field `{name}` to attribute the error to"),
+                ));
             }
         }
+    }
+    Ok(())
+}
+
+fn members(fields: &Fields) -> Vec<Member> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(idx, field)| member(idx, field))
+        .collect()
+}
+
+fn no_field_option(tokens: &TokenStream) -> Result<()> {
+    match parse_rules(tokens)?
+        .iter()
+        .find(|rule| rule.field.is_some())
+    {
+        Some(rule) => Err(Error::new_spanned(
+            &rule.expr,
+            "`field` is only supported on container rules that receive `&Self`",
+        )),
+        None => Ok(()),
+    }
+}
+
+pub fn is_newtype(fields: &Fields) -> bool {
+    matches!(fields, Fields::Unnamed(f) if f.unnamed.len() == 1)
+}
+
+fn container_rules(t: &mut TokenStream, all: bool, attrs: &[Attribute]) {
+    let Some(input) = get_container_rules(attrs) else {
+        return;
+    };
+    for rule in parse_rules(&input).unwrap_or_default() {
+        let path = quote(|t| match &rule.field {
+            Some(field) => {
+                let segment = field_segment(field);
+                quote!(t, { &[#segment] });
+            }
+            None => {
+                quote!(t, { &[] });
+            }
+        });
+        let value = quote(|t| {
+            quote!(t, { self });
+        });
+        rule_call(t, all, &rule, &path, &value);
+    }
+}
+
+fn rules(
+    t: &mut TokenStream,
+    all: bool,
+    input: &TokenStream,
+    path: &dyn ToTokens,
+    value: &dyn ToTokens,
+) {
+    for rule in parse_rules(input).unwrap_or_default() {
+        rule_call(t, all, &rule, path, value);
+    }
+}
+
+fn rule_call(
+    t: &mut TokenStream,
+    all: bool,
+    rule: &Rule,
+    path: &dyn ToTokens,
+    value: &dyn ToTokens,
+) {
+    let input = &rule.expr;
+    if is_nested(input) {
+        if all {
+            quote!(t, { ::validex::__nested_all(#path, #value, &mut __errors); });
+        } else {
+            quote!(t, { ::validex::__nested(#path, #value)?; });
+        }
+        return;
+    }
+    let result = quote(|t| {
+        quote!(t, {
+            ::validex::Check::check(&#input, #value).map_err(|err| {
+                use ::validex::{__OpaqueKind as _, __ParamsKind as _};
+                (&err).__kind().boxed(err)
+            })
+        });
+    });
+    let call = quote(|t| {
+        if rule.code.is_none() && rule.message.is_none() {
+            quote!(t, { ::validex::__field(#path, #result) });
+        } else {
+            let code = option(&rule.code);
+            let message = option(&rule.message);
+            quote!(t, { ::validex::__custom(#path, #code, #message, #result) });
+        }
     });
+    if all {
+        quote!(t, {
+            if let ::std::result::Result::Err(err) = #call {
+                __errors.push(err);
+            }
+        });
+    } else {
+        quote!(t, { #call?; });
+    }
+}
+
+pub fn option(lit: &Option<LitStr>) -> TokenStream {
+    let mut t = TokenStream::new();
+    match lit {
+        Some(lit) => {
+            quote!(t, { ::std::option::Option::Some(#lit) });
+        }
+        None => {
+            quote!(t, { ::std::option::Option::None });
+        }
+    }
     t
 }
 
+pub fn is_nested(input: &TokenStream) -> bool {
+    let mut tokens = input.clone().into_iter();
+    matches!(
+        (tokens.next(), tokens.next()),
+        (Some(TokenTree::Ident(ident)), None) if ident == "nested"
+    )
+}
+
 pub fn get_validex_field(field: &Field) -> Option<&TokenStream> {
-    field.attrs.iter().find_map(|attr| match &attr.meta {
+    get_check_attr(&field.attrs)
+}
+
+pub fn get_container_rules(attrs: &[Attribute]) -> Option<TokenStream> {
+    let tokens = get_check_attr(attrs)?;
+    let mut rules = TokenStream::new();
+    let mut first = true;
+    split_comma(tokens, |tokens| {
+        let leading = std::mem::take(&mut first);
+        if leading && matches!(parse_option(&tokens), Some((name, _)) if name == "error") {
+            return;
+        }
+        if !rules.is_empty() {
+            quote!(rules, { , });
+        }
+        rules.extend(tokens);
+    });
+    (!rules.is_empty()).then_some(rules)
+}
+
+pub fn get_error_type(attrs: &[Attribute]) -> Result<Option<Ident>> {
+    let Some(tokens) = get_check_attr(attrs) else {
+        return Ok(None);
+    };
+    let mut error = Ok(None);
+    let mut first = true;
+    split_comma(tokens, |tokens| {
+        if std::mem::take(&mut first)
+            && let Some((name, value)) = parse_option(&tokens)
+            && name == "error"
+        {
+            error = parse2(value).map(Some);
+        }
+    });
+    error
+}
+
+fn get_check_attr(attrs: &[Attribute]) -> Option<&TokenStream> {
+    attrs.iter().find_map(|attr| match &attr.meta {
         Meta::List(kv) => kv.path.is_ident("check").then_some(&kv.tokens),
         _ => None,
     })
 }
 
-fn split_comma(tokens: &TokenStream, mut f: impl FnMut(TokenStream)) {
+pub fn member(idx: usize, field: &Field) -> Member {
+    match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(idx)),
+    }
+}
+
+fn segment(idx: usize, field: &Field) -> TokenStream {
+    let mut t = TokenStream::new();
+    match &field.ident {
+        Some(ident) => {
+            let name = ident.to_string();
+            quote!(t, { ::validex::errors::Segment::Field(#name) });
+        }
+        None => {
+            quote!(t, { ::validex::errors::Segment::Position(#idx) });
+        }
+    }
+    t
+}
+
+fn field_segment(field: &Member) -> TokenStream {
+    let mut t = TokenStream::new();
+    match field {
+        Member::Named(ident) => {
+            let name = ident.to_string();
+            quote!(t, { ::validex::errors::Segment::Field(#name) });
+        }
+        Member::Unnamed(Index { index, .. }) => {
+            let index = *index as usize;
+            quote!(t, { ::validex::errors::Segment::Position(#index) });
+        }
+    }
+    t
+}
+
+pub fn binding(idx: usize) -> Ident {
+    Ident::new(&format!("__field{idx}"), Span::call_site())
+}
+
+pub fn split_comma(tokens: &TokenStream, mut f: impl FnMut(TokenStream)) {
     let tokens = tokens.clone().into_iter();
     let mut split = TokenStream::new();
     for tt in tokens {
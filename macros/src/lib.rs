@@ -1,8 +1,36 @@
 mod check;
+#[cfg(feature = "regex")]
+mod pattern;
+mod typed;
 
 use proc_macro::TokenStream;
 
+/// Derives `validex::Validate` from `#[check(...)]` attributes.
+///
+/// - On a field, each comma separated rule is checked against the field value.
+///   `nested` validates the field using its own `Validate` implementation.
+/// - Follow a rule with `code = "..."` and/or `message = "..."` to attach them to its error.
+/// - On a newtype, container rules are checked against the inner value, before the
+///   field rules, and `field = name` is not accepted.
+/// - On any other struct or enum, container rules receive `&Self` and run after the field
+///   rules. Follow a rule with `field = name` to attribute its error to that field, which
+///   must exist on the struct or on one of the enum variants.
+/// - Start the container attribute with `error = Name` to also generate a `Name` enum,
+///   with one variant per checked field, and implement `validex::ValidateTyped`,
+///   whose `check_typed` method returns it. Variants hold the rule error as an opaque
+///   type, exposing only its `Display` and `validex::errors::Params`.
 #[proc_macro_derive(Check, attributes(check))]
 pub fn validex(input: TokenStream) -> TokenStream {
     check::expand(&syn::parse_macro_input!(input)).into()
 }
+
+/// Checks a regular expression at compile time and expands to a `validex::Pattern`
+/// whose compiled expression is kept in a `static`, compiled once on first use.
+///
+/// Requires the `regex` feature.
+#[cfg(feature = "regex")]
+#[allow(non_snake_case)]
+#[proc_macro]
+pub fn Pattern(input: TokenStream) -> TokenStream {
+    pattern::expand(&syn::parse_macro_input!(input)).into()
+}
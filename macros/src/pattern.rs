@@ -0,0 +1,17 @@
+use quote2::proc_macro2::TokenStream;
+use quote2::{Quote, quote};
+use syn::*;
+
+pub fn expand(pattern: &LitStr) -> TokenStream {
+    if let Err(err) = regex_syntax::Parser::new().parse(&pattern.value()) {
+        return Error::new(pattern.span(), err).to_compile_error();
+    }
+    let mut t = TokenStream::new();
+    quote!(t, {
+        {
+            static CACHE: ::validex::__PatternCache = ::validex::__PatternCache::new();
+            ::validex::Pattern::__cached(#pattern, &CACHE)
+        }
+    });
+    t
+}
@@ -0,0 +1,373 @@
+use crate::check::*;
+use quote2::proc_macro2::{Span, TokenStream};
+use quote2::{Quote, ToTokens, quote};
+use syn::*;
+
+struct Slot {
+    variant: Ident,
+    label: String,
+    rules: Vec<Rule>,
+    value: TokenStream,
+}
+
+pub fn expand(input: &DeriveInput, error: &Ident) -> TokenStream {
+    let DeriveInput {
+        vis,
+        ident,
+        generics,
+        data,
+        attrs,
+        ..
+    } = input;
+
+    let mut slots = Vec::new();
+    let container = get_container_rules(attrs)
+        .and_then(|tokens| parse_rules(&tokens).ok())
+        .unwrap_or_default();
+
+    let body = match data {
+        Data::Struct(DataStruct { fields, .. }) => {
+            let newtype = is_newtype(fields);
+            let mut container = Some(container);
+            for (idx, field) in fields.iter().enumerate() {
+                let member = member(idx, field);
+                let mut value = TokenStream::new();
+                quote!(value, { &self.#member });
+                if newtype && let Some(rules) = container.take() {
+                    push_container(&mut slots, ident, rules, value.clone());
+                }
+                let Some(tokens) = get_validex_field(field) else {
+                    continue;
+                };
+                slots.push(Slot {
+                    variant: variant_name("", idx, field),
+                    label: label(idx, field),
+                    rules: parse_rules(tokens).unwrap_or_default(),
+                    value,
+                });
+            }
+            let start = slots.len();
+            push_container(
+                &mut slots,
+                ident,
+                container.unwrap_or_default(),
+                self_value(),
+            );
+            let checks = checks(error, &slots, 0..start);
+            let container = checks_from(error, &slots, start);
+            let mut t = TokenStream::new();
+            quote!(t, { #checks #container });
+            t
+        }
+        Data::Enum(DataEnum { variants, .. }) => {
+            let mut arms = Vec::new();
+            for variant in variants {
+                let start = slots.len();
+                let bindings = quote(|t| {
+                    for (idx, field) in variant.fields.iter().enumerate() {
+                        if get_validex_field(field).is_some() {
+                            let member = member(idx, field);
+                            let binding = binding(idx);
+                            quote!(t, { #member: #binding, });
+                        }
+                    }
+                });
+                for (idx, field) in variant.fields.iter().enumerate() {
+                    let Some(tokens) = get_validex_field(field) else {
+                        continue;
+                    };
+                    let binding = binding(idx);
+                    let mut value = TokenStream::new();
+                    quote!(value, { #binding });
+                    slots.push(Slot {
+                        variant: variant_name(&variant.ident.to_string(), idx, field),
+                        label: format!("{}.{}", variant.ident, label(idx, field)),
+                        rules: parse_rules(tokens).unwrap_or_default(),
+                        value,
+                    });
+                }
+                let name = &variant.ident;
+                let checks = checks(error, &slots, start..slots.len());
+                let mut arm = TokenStream::new();
+                quote!(arm, { Self::#name { #bindings .. } => { #checks } });
+                arms.push(arm);
+            }
+            let start = slots.len();
+            push_container(&mut slots, ident, container, self_value());
+            let container = checks_from(error, &slots, start);
+            let arms = quote(|t| {
+                for arm in &arms {
+                    arm.to_tokens(t);
+                }
+            });
+            let mut t = TokenStream::new();
+            if variants.is_empty() {
+                quote!(t, { match *self {} });
+            } else {
+                quote!(t, { match self { #arms } });
+            }
+            quote!(t, { #container });
+            t
+        }
+        Data::Union(data) => return union_error(data).to_compile_error(),
+    };
+    if let Err(err) = unique_variants(error, &slots) {
+        return err.to_compile_error();
+    }
+
+    let params: Vec<Ident> = (0..slots.len())
+        .map(|idx| Ident::new(&format!("E{idx}"), Span::call_site()))
+        .collect();
+    let params_list = quote(|t| {
+        for param in &params {
+            quote!(t, { #param, });
+        }
+    });
+    let bounded = |bound: TokenStream| {
+        let params = &params;
+        quote(move |t| {
+            for param in params {
+                quote!(t, { #param: #bound, });
+            }
+        })
+    };
+    let mut display_bound = TokenStream::new();
+    quote!(display_bound, { ::std::fmt::Display });
+    let mut error_bound = TokenStream::new();
+    quote!(error_bound, { ::std::fmt::Debug + ::std::fmt::Display });
+    let mut params_bound = TokenStream::new();
+    quote!(params_bound, { ::validex::errors::Params });
+    let display_bounds = bounded(display_bound);
+    let error_bounds = bounded(error_bound);
+    let params_bounds = bounded(params_bound);
+    let opaque = quote(|t| {
+        for _ in &slots {
+            quote!(t, {
+                impl ::std::fmt::Debug + ::std::fmt::Display + ::validex::errors::Params,
+            });
+        }
+    });
+
+    let variants = quote(|t| {
+        for (slot, param) in slots.iter().zip(&params) {
+            let variant = &slot.variant;
+            let doc = match slot.label.as_str() {
+                "" => format!("A rule of [`{ident}`] failed."),
+                label => format!("A rule of `{label}` failed."),
+            };
+            quote!(t, { #[doc = #doc] #variant(#param), });
+        }
+    });
+    let match_each = |f: fn(&mut TokenStream, &Slot)| {
+        let slots = &slots;
+        quote(move |t| {
+            if slots.is_empty() {
+                quote!(t, { match *self {} });
+                return;
+            }
+            let arms = quote(|t| {
+                for slot in slots {
+                    let variant = &slot.variant;
+                    let body = quote(|t| f(t, slot));
+                    quote!(t, { Self::#variant(err) => { #body } });
+                }
+            });
+            quote!(t, { match self { #arms } });
+        })
+    };
+    let display = match_each(|t, slot| {
+        if !slot.label.is_empty() {
+            let prefix = format!("{} -> ", slot.label);
+            quote!(t, { f.write_str(#prefix)?; });
+        }
+        quote!(t, { ::std::fmt::Display::fmt(err, f) });
+    });
+    let code = match_each(|t, _| {
+        quote!(t, { ::validex::errors::Params::code(err) });
+    });
+    let params_fn = match_each(|t, _| {
+        quote!(t, { ::validex::errors::Params::params(err, f) });
+    });
+    let path = match_each(|t, _| {
+        quote!(t, { ::validex::errors::Params::path(err, f) });
+    });
+    let children = match_each(|t, _| {
+        quote!(t, { ::validex::errors::Params::children(err, f) });
+    });
+
+    let doc = format!(
+        "Typed error returned by the [`check_typed`](::validex::ValidateTyped::check_typed) of [`{ident}`].\n\n\
+         Each variant holds the error of the rule that failed, exposed through \
+         [`Params`](::validex::errors::Params)."
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut t = TokenStream::new();
+    quote!(t, {
+        #[doc = #doc]
+        #[derive(Debug)]
+        #vis enum #error<#params_list> {
+            #variants
+        }
+
+        impl<#display_bounds> ::std::fmt::Display for #error<#params_list> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                #display
+            }
+        }
+
+        impl<#error_bounds> ::std::error::Error for #error<#params_list> {}
+
+        impl<#params_bounds> ::validex::errors::Params for #error<#params_list> {
+            fn code(&self) -> ::std::option::Option<&'static str> {
+                #code
+            }
+            fn params(&self, f: &mut dyn FnMut(&'static str, &dyn ::std::fmt::Debug)) {
+                #params_fn
+            }
+            fn children(&self, f: &mut dyn FnMut(&dyn ::validex::errors::ValidationError)) {
+                #children
+            }
+            fn path(&self, f: &mut dyn FnMut(::validex::errors::Segment)) {
+                #path
+            }
+        }
+
+        impl #impl_generics ::validex::ValidateTyped for #ident #ty_generics #where_clause {
+            #[allow(refining_impl_trait)]
+            fn check_typed(&self) -> ::std::result::Result<(), #error<#opaque>> {
+                #body
+                ::std::result::Result::Ok(())
+            }
+        }
+    });
+    t
+}
+
+fn unique_variants(error: &Ident, slots: &[Slot]) -> Result<()> {
+    let describe = |slot: &Slot| match slot.label.as_str() {
+        "" => "the container rules".to_string(),
+        label => format!("`{label}`"),
+    };
+    for (idx, slot) in slots.iter().enumerate() {
+        if let Some(other) = slots[..idx]
+            .iter()
+            .find(|other| other.variant == slot.variant)
+        {
+            return Err(Error::new(
+                error.span(),
+                format!(
+                    "`{error}` would have two `{}` variants, for {} and {}",
+                    slot.variant,
+                    describe(other),
+                    describe(slot),
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn self_value() -> TokenStream {
+    let mut value = TokenStream::new();
+    quote!(value, { self });
+    value
+}
+
+fn push_container(slots: &mut Vec<Slot>, ident: &Ident, rules: Vec<Rule>, value: TokenStream) {
+    if rules.is_empty() {
+        return;
+    }
+    slots.push(Slot {
+        variant: ident.clone(),
+        label: String::new(),
+        rules,
+        value,
+    });
+}
+
+fn checks_from<'a>(error: &'a Ident, slots: &'a [Slot], start: usize) -> impl ToTokens + 'a {
+    checks(error, slots, start..slots.len())
+}
+
+fn checks<'a>(
+    error: &'a Ident,
+    slots: &'a [Slot],
+    range: std::ops::Range<usize>,
+) -> impl ToTokens + 'a {
+    quote(move |t| {
+        for slot in &slots[range.clone()] {
+            let Slot {
+                variant,
+                rules,
+                value,
+                ..
+            } = slot;
+            for (idx, rule) in rules.iter().enumerate() {
+                let call = quote(|t| {
+                    let expr = &rule.expr;
+                    if is_nested(expr) {
+                        quote!(t, { ::validex::Validate::check(#value) });
+                    } else if rule.code.is_none() && rule.message.is_none() {
+                        quote!(t, { ::validex::Check::check(&#expr, #value) });
+                    } else {
+                        let code = option(&rule.code);
+                        let message = option(&rule.message);
+                        quote!(t, {
+                            ::validex::Check::check(
+                                &::validex::Custom { rule: #expr, code: #code, message: #message },
+                                #value,
+                            )
+                        });
+                    }
+                });
+                let wrapped = either(idx, rules.len());
+                quote!(t, {
+                    if let ::std::result::Result::Err(err) = #call {
+                        return ::std::result::Result::Err(#error::#variant(#wrapped));
+                    }
+                });
+            }
+        }
+    })
+}
+
+fn either(idx: usize, len: usize) -> TokenStream {
+    let mut t = TokenStream::new();
+    if idx + 1 < len {
+        quote!(t, { ::validex::errors::Either::Left(err) });
+    } else {
+        quote!(t, { err });
+    }
+    for _ in 0..idx {
+        let inner = t;
+        t = TokenStream::new();
+        quote!(t, { ::validex::errors::Either::Right(#inner) });
+    }
+    t
+}
+
+fn variant_name(prefix: &str, idx: usize, field: &Field) -> Ident {
+    let mut name = prefix.to_string();
+    match &field.ident {
+        Some(ident) => {
+            let ident = ident.to_string();
+            for word in ident.trim_start_matches("r#").split('_') {
+                let mut chars = word.chars();
+                if let Some(first) = chars.next() {
+                    name.extend(first.to_uppercase());
+                    name.push_str(chars.as_str());
+                }
+            }
+        }
+        None if prefix.is_empty() => name = format!("Field{idx}"),
+        None => name.push_str(&idx.to_string()),
+    }
+    Ident::new(&name, Span::call_site())
+}
+
+fn label(idx: usize, field: &Field) -> String {
+    match &field.ident {
+        Some(ident) => ident.to_string().trim_start_matches("r#").to_string(),
+        None => idx.to_string(),
+    }
+}
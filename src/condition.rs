@@ -2,6 +2,9 @@ use crate::*;
 
 /// A condition that requires all sub-conditions to be met.
 ///
+/// Sub-conditions are given as a tuple, or as an array, slice or `Vec` of rules
+/// of the same type (see [`DynRule`] to mix rule types). An empty list passes.
+///
 /// ## Example
 ///
 /// ```rust
@@ -14,6 +17,9 @@ pub struct All<V>(pub V);
 
 /// A condition that requires any sub-condition to be met.
 ///
+/// Like [`All`], it accepts a tuple, array, slice or `Vec` of rules. An empty
+/// list fails, with an empty [`Errors`](errors::Errors) that displays as `no rules to satisfy`.
+///
 /// ## Example
 ///
 /// ```rust
@@ -65,6 +71,103 @@ pub struct Not<V>(pub V);
 /// ```
 pub struct Maybe<T>(pub T);
 
+/// Validates a nested value using its [`Validate`] implementation.
+///
+/// The path of the nested error is merged into the [`Path`](errors::Path) of the field,
+/// e.g. `address.city`, also inside [`Each`] or [`Maybe`]. In a derived type,
+/// `#[check(nested)]` also works for collections of [`Validate`] types and collects
+/// every nested error with `check_all()`.
+///
+/// ## Example
+///
+/// ```rust
+/// # use validex::*;
+/// #[derive(Check)]
+/// struct Address {
+///   #[check(Length(1..))]
+///   city: String,
+/// }
+///
+/// #[derive(Check)]
+/// struct User {
+///   #[check(Valid)]
+///   address: Address,
+///   #[check(Maybe(Valid))]
+///   billing: Option<Address>,
+/// }
+/// ```
+pub struct Valid;
+
+impl<'a, T> Check<&'a T> for Valid
+where
+    T: ?Sized + Validate,
+{
+    type Error = errors::NestedError<'a>;
+    #[inline]
+    fn check(&self, val: &'a T) -> Result<(), Self::Error> {
+        val.check().map_err(|err| errors::NestedError(err.into()))
+    }
+}
+
+/// Adapts a [`Check`] rule, such as a function returning a `Result` or [`Valid`],
+/// to [`Verify`], so it can be combined with [`Not`], [`All`] and [`Any`].
+///
+/// ## Example
+///
+/// ```rust
+/// # use validex::*;
+/// fn is_unlucky(id: &u32) -> Result<(), &'static str> {
+///     if *id == 13 {
+///         return Err("13 is an unlucky number");
+///     }
+///     Ok(())
+/// }
+///
+/// #[derive(Check)]
+/// struct Input {
+///   #[check(Any((Checked(is_unlucky), Range(100..))))]
+///   id: u32,
+/// }
+///
+/// assert!(Input { id: 7 }.check().is_ok());
+/// assert!(Input { id: 13 }.check().is_err());
+/// assert!(Not(Checked(is_unlucky)).verify(&13));
+/// ```
+pub struct Checked<V>(pub V);
+
+impl<T, V> Verify<T> for Checked<V>
+where
+    V: Check<T>,
+{
+    type Error = errors::CheckedError<V::Error>;
+    #[inline]
+    fn verify(&self, val: T) -> bool {
+        self.0.check(val).is_ok()
+    }
+    #[inline]
+    fn error(&self, val: T) -> Self::Error {
+        match self.0.check(val) {
+            Ok(()) => errors::CheckedError::Passed("checked"),
+            Err(err) => errors::CheckedError::Failed(err),
+        }
+    }
+    #[inline]
+    fn try_verify(&self, val: T) -> Result<(), Self::Error> {
+        self.0.check(val).map_err(errors::CheckedError::Failed)
+    }
+}
+
+impl<T, V> Check<T> for Checked<V>
+where
+    V: Check<T>,
+{
+    type Error = errors::CheckedError<V::Error>;
+    #[inline]
+    fn check(&self, val: T) -> Result<(), Self::Error> {
+        self.0.check(val).map_err(errors::CheckedError::Failed)
+    }
+}
+
 impl<T, V> Verify<T> for Not<V>
 where
     V: Verify<T>,
@@ -105,6 +208,10 @@ where
     }
 }
 
+macro_rules! one {
+    [$_:tt] => [1];
+}
+
 macro_rules! t {
     [$($ty:tt: $idx:tt)*] => [
         #[doc(hidden)]
@@ -135,6 +242,14 @@ macro_rules! t {
                     $(self.0.$idx.error(val).into(),)*
                 ]))
             }
+            #[allow(non_snake_case)]
+            fn try_verify(&self, val: &'a T) -> Result<(), Self::Error> {
+                $(let $ty = match self.0.$idx.try_verify(val) {
+                    Ok(()) => return Ok(()),
+                    Err(err) => err,
+                };)*
+                Err(errors::Errors(Box::new([$($ty.into(),)*])))
+            }
         }
 
         #[doc(hidden)]
@@ -148,6 +263,39 @@ macro_rules! t {
                 check(self, val)
             }
         }
+
+        impl<'a, T, $($ty),*> Rules<'a, T> for ($($ty,)*)
+        where
+            T: ?Sized + 'a,
+            $($ty: Verify<&'a T>,)*
+            $($ty::Error: Into<DynError<'a>>,)*
+        {
+            type Failures = ($(Option<$ty::Error>,)*);
+            fn __len(&self) -> usize {
+                0 $(+ one!($idx))*
+            }
+            fn __passed(&self, val: &'a T) -> usize {
+                0 $(+ self.$idx.verify(val) as usize)*
+            }
+            fn __tally(&self, val: &'a T) -> (usize, Self::Failures) {
+                let mut passed = 0;
+                let failures = ($(match self.$idx.try_verify(val) {
+                    Ok(()) => {
+                        passed += 1;
+                        None
+                    }
+                    Err(err) => Some(err),
+                },)*);
+                (passed, failures)
+            }
+            fn __boxed(failures: Self::Failures) -> Box<[DynError<'a>]> {
+                let mut boxed = Vec::new();
+                $(if let Some(err) = failures.$idx {
+                    boxed.push(err.into());
+                })*
+                boxed.into()
+            }
+        }
     ]
 }
 
@@ -166,8 +314,28 @@ macro_rules! all {
                 $( self.0.$idx.verify(val) &&)* self.0.$last_idx.verify(val)
             }
             fn error(&self, val: &'a T) -> Self::Error {
-                $( if !self.0.$idx.verify(val) { return self.0.$idx.error(val).into(); } )*
-                self.0.$last_idx.error(val).into()
+                match self.try_verify(val) {
+                    Err(err) => err,
+                    Ok(()) => errors::CheckedError::<std::convert::Infallible>::Passed("all").into(),
+                }
+            }
+            fn try_verify(&self, val: &'a T) -> Result<(), Self::Error> {
+                $( self.0.$idx.try_verify(val).map_err($ty::Error::into)?; )*
+                self.0.$last_idx.try_verify(val).map_err($last_ty::Error::into)
+            }
+        }
+
+        #[doc(hidden)]
+        impl<'a, T, $($ty,)* $last_ty> Check<&'a T> for All<($($ty,)* $last_ty,)>
+        where
+            $($ty: Verify<&'a T>,)*
+            $last_ty: Verify<&'a T>,
+            $( $ty::Error: Into<DynError<'a>>, )*
+            $last_ty::Error: Into<DynError<'a>>
+        {
+            type Error = DynError<'a>;
+            fn check(&self, val: &'a T) -> Result<(), Self::Error> {
+                check(self, val)
             }
         }
     ]
@@ -233,6 +401,13 @@ where
     fn error(&self, val: T) -> Self::Error {
         self.0.0.error(val)
     }
+    #[inline]
+    fn try_verify(&self, val: T) -> Result<(), Self::Error>
+    where
+        T: Copy,
+    {
+        self.0.0.try_verify(val)
+    }
 }
 
 // #[cfg_attr(docsrs, doc(fake_variadic))]
@@ -251,6 +426,27 @@ where
     fn error(&self, val: T) -> Self::Error {
         self.0.0.error(val)
     }
+    #[inline]
+    fn try_verify(&self, val: T) -> Result<(), Self::Error>
+    where
+        T: Copy,
+    {
+        self.0.0.try_verify(val)
+    }
+}
+
+// #[cfg_attr(docsrs, doc(fake_variadic))]
+#[doc = "This trait is implemented for tuples up to 16 items long."]
+impl<'a, T, V0> Check<&'a T> for All<(V0,)>
+where
+    T: ?Sized,
+    V0: Verify<&'a T>,
+{
+    type Error = V0::Error;
+    #[inline]
+    fn check(&self, val: &'a T) -> Result<(), Self::Error> {
+        check(self, val)
+    }
 }
 
 // #[cfg_attr(docsrs, doc(fake_variadic))]
@@ -266,3 +462,535 @@ where
         check(self, val)
     }
 }
+
+/// A boxed rule with a type-erased error, so rules of different types can be
+/// stored together, e.g. in a `Vec<DynRule<T>>` combined with [`Any`] or [`All`].
+///
+/// ## Example
+///
+/// ```rust
+/// # use validex::*;
+/// let rules: Vec<DynRule<u32>> = vec![
+///     DynRule::new(Range(10..=20)),
+///     DynRule::new(42),
+/// ];
+/// let rule = Any(rules);
+/// assert!(rule.verify(&42));
+/// assert!(!rule.verify(&7));
+/// ```
+#[allow(clippy::type_complexity)]
+pub struct DynRule<'r, T: ?Sized>(
+    Box<
+        dyn for<'a> Verify<&'a T, Error = Box<dyn errors::ValidationError + 'a>> + Send + Sync + 'r,
+    >,
+);
+
+/// Erases the error type of a rule, see [`DynRule`].
+struct Erased<V>(V);
+
+impl<'a, T, V> Verify<&'a T> for Erased<V>
+where
+    T: ?Sized,
+    V: Verify<&'a T>,
+    V::Error: errors::ValidationError + 'a,
+{
+    type Error = Box<dyn errors::ValidationError + 'a>;
+    #[inline]
+    fn verify(&self, val: &'a T) -> bool {
+        self.0.verify(val)
+    }
+    #[inline]
+    fn error(&self, val: &'a T) -> Self::Error {
+        Box::new(self.0.error(val))
+    }
+    #[inline]
+    fn try_verify(&self, val: &'a T) -> Result<(), Self::Error> {
+        self.0
+            .try_verify(val)
+            .map_err(|err| Box::new(err) as Self::Error)
+    }
+}
+
+impl<'r, T: ?Sized> DynRule<'r, T> {
+    /// Boxes a rule, erasing its error type.
+    pub fn new<V>(rule: V) -> Self
+    where
+        V: for<'a> Verify<&'a T, Error: errors::ValidationError + 'a> + Send + Sync + 'r,
+    {
+        DynRule(Box::new(Erased(rule)))
+    }
+}
+
+impl<'a, T> Verify<&'a T> for DynRule<'_, T>
+where
+    T: ?Sized,
+{
+    type Error = Box<dyn errors::ValidationError + 'a>;
+    #[inline]
+    fn verify(&self, val: &'a T) -> bool {
+        self.0.verify(val)
+    }
+    #[inline]
+    fn error(&self, val: &'a T) -> Self::Error {
+        self.0.error(val)
+    }
+    #[inline]
+    fn try_verify(&self, val: &'a T) -> Result<(), Self::Error> {
+        self.0.try_verify(val)
+    }
+}
+
+impl<'a, T> Check<&'a T> for DynRule<'_, T>
+where
+    T: ?Sized,
+{
+    type Error = Box<dyn errors::ValidationError + 'a>;
+    fn check(&self, val: &'a T) -> Result<(), Self::Error> {
+        check(self, val)
+    }
+}
+
+macro_rules! list {
+    [$([$($p:tt)*] => $ty:ty)*] => [$(
+        impl<'a, T, V, $($p)*> Verify<&'a T> for Any<$ty>
+        where
+            T: ?Sized,
+            V: Verify<&'a T>,
+            V::Error: Into<DynError<'a>>,
+        {
+            type Error = errors::Errors<'a>;
+            fn verify(&self, val: &'a T) -> bool {
+                self.0.iter().any(|rule| rule.verify(val))
+            }
+            fn error(&self, val: &'a T) -> Self::Error {
+                errors::Errors(self.0.iter().map(|rule| rule.error(val).into()).collect())
+            }
+            fn try_verify(&self, val: &'a T) -> Result<(), Self::Error> {
+                let mut errors = Vec::new();
+                for rule in self.0.iter() {
+                    match rule.try_verify(val) {
+                        Ok(()) => return Ok(()),
+                        Err(err) => errors.push(err.into()),
+                    }
+                }
+                Err(errors::Errors(errors.into()))
+            }
+        }
+
+        impl<'a, T, V, $($p)*> Check<&'a T> for Any<$ty>
+        where
+            T: ?Sized,
+            V: Verify<&'a T>,
+            V::Error: Into<DynError<'a>>,
+        {
+            type Error = errors::Errors<'a>;
+            fn check(&self, val: &'a T) -> Result<(), Self::Error> {
+                check(self, val)
+            }
+        }
+
+        impl<'a, T, V, $($p)*> Rules<'a, T> for $ty
+        where
+            T: ?Sized + 'a,
+            V: Verify<&'a T>,
+            V::Error: Into<DynError<'a>>,
+        {
+            type Failures = Vec<V::Error>;
+            fn __len(&self) -> usize {
+                self.len()
+            }
+            fn __passed(&self, val: &'a T) -> usize {
+                self.iter().filter(|rule| rule.verify(val)).count()
+            }
+            fn __tally(&self, val: &'a T) -> (usize, Self::Failures) {
+                let mut passed = 0;
+                let mut failures = Vec::new();
+                for rule in self.iter() {
+                    match rule.try_verify(val) {
+                        Ok(()) => passed += 1,
+                        Err(err) => failures.push(err),
+                    }
+                }
+                (passed, failures)
+            }
+            fn __boxed(failures: Self::Failures) -> Box<[DynError<'a>]> {
+                failures.into_iter().map(V::Error::into).collect()
+            }
+        }
+
+        impl<'a, T, V, $($p)*> Verify<&'a T> for All<$ty>
+        where
+            T: ?Sized,
+            V: Verify<&'a T>,
+        {
+            type Error = errors::CheckedError<V::Error>;
+            fn verify(&self, val: &'a T) -> bool {
+                self.0.iter().all(|rule| rule.verify(val))
+            }
+            fn error(&self, val: &'a T) -> Self::Error {
+                self.try_verify(val).err().unwrap_or(errors::CheckedError::Passed("all"))
+            }
+            fn try_verify(&self, val: &'a T) -> Result<(), Self::Error> {
+                self.0
+                    .iter()
+                    .try_for_each(|rule| rule.try_verify(val))
+                    .map_err(errors::CheckedError::Failed)
+            }
+        }
+
+        impl<'a, T, V, $($p)*> Check<&'a T> for All<$ty>
+        where
+            T: ?Sized,
+            V: Verify<&'a T>,
+        {
+            type Error = V::Error;
+            fn check(&self, val: &'a T) -> Result<(), Self::Error> {
+                self.0.iter().try_for_each(|rule| rule.try_verify(val))
+            }
+        }
+    )*];
+}
+
+list! {
+    [const N: usize] => [V; N]
+    [] => &[V]
+    [] => Vec<V>
+}
+
+/// A list of rules whose passes can be counted, implemented for tuples up to
+/// 16 items long and for arrays, slices and vectors of rules.
+///
+/// It is exported with the rules, so its methods are prefixed to not shadow
+/// methods of the same name, such as [`Count::count`], on other types.
+#[doc(hidden)]
+pub trait Rules<'a, T: ?Sized + 'a> {
+    /// The errors of the failed rules, kept typed until they are reported.
+    type Failures;
+    /// The number of rules.
+    fn __len(&self) -> usize;
+    /// The number of rules that pass for `val`.
+    fn __passed(&self, val: &'a T) -> usize;
+    /// The number of rules that pass for `val`, and the errors of those that fail,
+    /// in a single pass.
+    fn __tally(&self, val: &'a T) -> (usize, Self::Failures);
+    /// Boxes the errors of the failed rules, once they are reported.
+    fn __boxed(failures: Self::Failures) -> Box<[DynError<'a>]>;
+}
+
+impl<'a, T, V0> Rules<'a, T> for (V0,)
+where
+    T: ?Sized + 'a,
+    V0: Verify<&'a T>,
+    V0::Error: Into<DynError<'a>>,
+{
+    type Failures = Option<V0::Error>;
+    fn __len(&self) -> usize {
+        1
+    }
+    fn __passed(&self, val: &'a T) -> usize {
+        self.0.verify(val) as usize
+    }
+    fn __tally(&self, val: &'a T) -> (usize, Self::Failures) {
+        match self.0.try_verify(val) {
+            Ok(()) => (1, None),
+            Err(err) => (0, Some(err)),
+        }
+    }
+    fn __boxed(failures: Self::Failures) -> Box<[DynError<'a>]> {
+        failures.into_iter().map(V0::Error::into).collect()
+    }
+}
+
+/// A condition that requires at least `n` sub-conditions to be met.
+///
+/// ## Example
+///
+/// ```rust
+/// # use validex::*;
+/// fn has_digit(s: &String) -> bool {
+///     s.chars().any(|c| c.is_ascii_digit())
+/// }
+///
+/// #[derive(Check)]
+/// struct Signup {
+///   #[check(AtLeast(2, (Length(12..), Pred(has_digit, "no digit"), Not(Lowercase))))]
+///   password: String,
+/// }
+///
+/// assert!(Signup { password: "hunter2".into() }.check().is_err());
+/// assert!(Signup { password: "Hunter2".into() }.check().is_ok());
+///
+/// let err = AtLeast(2, (Range(1..=5), Range(10..=20))).check(&3).unwrap_err();
+/// assert_eq!(err.to_string(), "expected at least 2 rules to pass, 1 passed: expected 3 in 10..=20");
+/// ```
+pub struct AtLeast<V>(pub usize, pub V);
+
+/// A condition that allows at most `n` sub-conditions to be met.
+///
+/// ## Example
+///
+/// ```rust
+/// # use validex::*;
+/// let condition = AtMost(1, (Range(0..=10), Range(5..=15), 7));
+/// assert!(condition.verify(&3));
+/// assert!(!condition.verify(&6));
+/// ```
+pub struct AtMost<V>(pub usize, pub V);
+
+/// A condition that requires exactly one sub-condition to be met.
+///
+/// ## Example
+///
+/// ```rust
+/// # use validex::*;
+/// let condition = ExactlyOne((Range(0..=10), Range(5..=15), 42));
+/// assert!(condition.verify(&3));
+/// assert!(condition.verify(&42));
+/// assert!(!condition.verify(&7));
+/// assert!(!condition.verify(&20));
+/// ```
+pub struct ExactlyOne<V>(pub V);
+
+/// A condition that requires exactly one of two sub-conditions to be met.
+///
+/// ## Example
+///
+/// ```rust
+/// # use validex::*;
+/// let condition = Xor((Range(0..=10), Range(5..=15)));
+/// assert!(condition.verify(&3));
+/// assert!(!condition.verify(&7));
+/// ```
+pub struct Xor<V>(pub V);
+
+fn count_error<'a, T, R>(
+    kind: errors::CountKind,
+    (min, max): (usize, usize),
+    passed: usize,
+    failures: R::Failures,
+) -> errors::CountError<'a>
+where
+    T: ?Sized + 'a,
+    R: ?Sized + Rules<'a, T>,
+{
+    errors::CountError {
+        kind,
+        passed,
+        min,
+        max,
+        // Too many rules passed, so the failures are not to blame.
+        failures: match passed > max {
+            true => Box::new([]),
+            false => R::__boxed(failures),
+        },
+    }
+}
+
+macro_rules! count {
+    [$([$($p:tt)*] $ty:ty where $rules:ty: $kind:ident |$this:ident, $n:pat_param| $list:expr => $bounds:expr;)*] => [$(
+        impl<'a, T, $($p)*> Verify<&'a T> for $ty
+        where
+            T: ?Sized,
+            $rules: Rules<'a, T>,
+        {
+            type Error = errors::CountError<'a>;
+            fn verify(&self, val: &'a T) -> bool {
+                let $this = self;
+                let $n = $list.__len();
+                let (min, max) = $bounds;
+                (min..=max).contains(&$list.__passed(val))
+            }
+            fn error(&self, val: &'a T) -> Self::Error {
+                let $this = self;
+                let $n = $list.__len();
+                let (passed, failures) = $list.__tally(val);
+                count_error::<T, $rules>(errors::CountKind::$kind, $bounds, passed, failures)
+            }
+            fn try_verify(&self, val: &'a T) -> Result<(), Self::Error> {
+                let $this = self;
+                let $n = $list.__len();
+                let (min, max) = $bounds;
+                let (passed, failures) = $list.__tally(val);
+                if (min..=max).contains(&passed) {
+                    return Ok(());
+                }
+                Err(count_error::<T, $rules>(errors::CountKind::$kind, (min, max), passed, failures))
+            }
+        }
+
+        impl<'a, T, $($p)*> Check<&'a T> for $ty
+        where
+            T: ?Sized,
+            $rules: Rules<'a, T>,
+        {
+            type Error = errors::CountError<'a>;
+            fn check(&self, val: &'a T) -> Result<(), Self::Error> {
+                check(self, val)
+            }
+        }
+    )*];
+}
+
+count! {
+    [V] AtLeast<V> where V: AtLeast |this, n| this.1 => (this.0, n);
+    [V] AtMost<V> where V: AtMost |this, _| this.1 => (0, this.0);
+    [V] ExactlyOne<V> where V: ExactlyOne |this, _| this.0 => (1, 1);
+    [A, B] Xor<(A, B)> where (A, B): Xor |this, _| this.0 => (1, 1);
+}
+
+/// Same as [`All`], but the error is statically typed instead of boxed, so
+/// nothing is allocated when validation fails.
+///
+/// The [`Check`] error of the failing sub-condition is nested in [`Either`](errors::Either),
+/// e.g. `Either<E0, Either<E1, E2>>` for three sub-conditions. As a [`Verify`] rule,
+/// it is wrapped in a [`CheckedError`](errors::CheckedError), which is
+/// [`Passed`](errors::CheckedError::Passed) when negated by [`Not`].
+///
+/// `#[derive(Check)]` boxes the error into a [`FieldError`](errors::FieldError)
+/// when [`Validate::check`] fails. To keep it unboxed, start the container
+/// attribute with `error = Name` and call [`ValidateTyped::check_typed`] instead.
+///
+/// ## Example
+///
+/// ```rust
+/// # use validex::{*, errors::Either};
+/// let condition = AllOf((Range(10..=20), Not(15)));
+/// assert!(condition.verify(&12));
+/// assert!(matches!(condition.check(&42), Err(Either::Left(_))));
+/// assert!(matches!(condition.check(&15), Err(Either::Right(_))));
+/// ```
+pub struct AllOf<V>(pub V);
+
+/// Same as [`Any`], but the error is statically typed instead of boxed, so
+/// nothing is allocated when validation fails.
+///
+/// The errors of every sub-condition are kept in a tuple, see [`AnyErrors`](errors::AnyErrors).
+/// Like [`AllOf`], it implements [`Check`] for sub-conditions that only implement
+/// [`Check`], such as [`Valid`], and its error is boxed by [`Validate::check`]
+/// but not by [`ValidateTyped::check_typed`].
+///
+/// ## Example
+///
+/// ```rust
+/// # use validex::*;
+/// let condition = AnyOf((Range(15..=20), Range(40..)));
+/// assert!(condition.verify(&17));
+/// assert!(condition.verify(&42));
+///
+/// let err = condition.check(&12).unwrap_err();
+/// assert_eq!(err.0.0.value, &12);
+/// ```
+pub struct AnyOf<V>(pub V);
+
+macro_rules! either {
+    [$last:ty] => [$last];
+    [$head:ty, $($rest:ty),+] => [errors::Either<$head, either![$($rest),+]>];
+}
+
+// Runs the rules in order, returning the error of the first failing one.
+macro_rules! first_err {
+    [$this:ident.$method:ident($val:ident); $last:tt] => [$this.0.$last.$method($val)];
+    [$this:ident.$method:ident($val:ident); $head:tt $($rest:tt)+] => [
+        match $this.0.$head.$method($val) {
+            Err(err) => Err(errors::Either::Left(err)),
+            Ok(()) => first_err![$this.$method($val); $($rest)+].map_err(errors::Either::Right),
+        }
+    ];
+}
+
+macro_rules! of {
+    [$($ty:tt: $idx:tt)*] => [
+        #[doc(hidden)]
+        impl<'a, T, $($ty),*> Verify<&'a T> for AllOf<($($ty,)*)>
+        where
+            T: ?Sized,
+            $($ty: Verify<&'a T>,)*
+        {
+            type Error = errors::CheckedError<either![$($ty::Error),*]>;
+            #[inline]
+            fn verify(&self, val: &'a T) -> bool {
+                $( self.0.$idx.verify(val) )&&*
+            }
+            fn error(&self, val: &'a T) -> Self::Error {
+                self.try_verify(val).err().unwrap_or(errors::CheckedError::Passed("all"))
+            }
+            #[inline]
+            fn try_verify(&self, val: &'a T) -> Result<(), Self::Error> {
+                first_err![self.try_verify(val); $($idx)*].map_err(errors::CheckedError::Failed)
+            }
+        }
+
+        #[doc(hidden)]
+        impl<'a, T, $($ty),*> Check<&'a T> for AllOf<($($ty,)*)>
+        where
+            T: ?Sized,
+            $($ty: Check<&'a T>,)*
+        {
+            type Error = either![$($ty::Error),*];
+            #[inline]
+            fn check(&self, val: &'a T) -> Result<(), Self::Error> {
+                first_err![self.check(val); $($idx)*]
+            }
+        }
+
+        #[doc(hidden)]
+        impl<'a, T, $($ty),*> Verify<&'a T> for AnyOf<($($ty,)*)>
+        where
+            T: ?Sized,
+            $($ty: Verify<&'a T>,)*
+        {
+            type Error = errors::AnyErrors<($($ty::Error,)*)>;
+            #[inline]
+            fn verify(&self, val: &'a T) -> bool {
+                $( self.0.$idx.verify(val) )||*
+            }
+            fn error(&self, val: &'a T) -> Self::Error {
+                errors::AnyErrors(($(self.0.$idx.error(val),)*))
+            }
+            #[allow(non_snake_case)]
+            #[inline]
+            fn try_verify(&self, val: &'a T) -> Result<(), Self::Error> {
+                $(let $ty = match self.0.$idx.try_verify(val) {
+                    Ok(()) => return Ok(()),
+                    Err(err) => err,
+                };)*
+                Err(errors::AnyErrors(($($ty,)*)))
+            }
+        }
+
+        #[doc(hidden)]
+        impl<'a, T, $($ty),*> Check<&'a T> for AnyOf<($($ty,)*)>
+        where
+            T: ?Sized,
+            $($ty: Check<&'a T>,)*
+        {
+            type Error = errors::AnyErrors<($($ty::Error,)*)>;
+            #[allow(non_snake_case)]
+            #[inline]
+            fn check(&self, val: &'a T) -> Result<(), Self::Error> {
+                $(let $ty = match self.0.$idx.check(val) {
+                    Ok(()) => return Ok(()),
+                    Err(err) => err,
+                };)*
+                Err(errors::AnyErrors(($($ty,)*)))
+            }
+        }
+    ]
+}
+
+of! { V0:0 }
+of! { V0:0 V1:1 }
+of! { V0:0 V1:1 V2:2 }
+of! { V0:0 V1:1 V2:2 V3:3 }
+of! { V0:0 V1:1 V2:2 V3:3 V4:4 }
+of! { V0:0 V1:1 V2:2 V3:3 V4:4 V5:5 }
+of! { V0:0 V1:1 V2:2 V3:3 V4:4 V5:5 V6:6 }
+of! { V0:0 V1:1 V2:2 V3:3 V4:4 V5:5 V6:6 V7:7 }
+of! { V0:0 V1:1 V2:2 V3:3 V4:4 V5:5 V6:6 V7:7 V8:8 }
+of! { V0:0 V1:1 V2:2 V3:3 V4:4 V5:5 V6:6 V7:7 V8:8 V9:9 }
+of! { V0:0 V1:1 V2:2 V3:3 V4:4 V5:5 V6:6 V7:7 V8:8 V9:9 V10:10 }
+of! { V0:0 V1:1 V2:2 V3:3 V4:4 V5:5 V6:6 V7:7 V8:8 V9:9 V10:10 V11:11 }
+of! { V0:0 V1:1 V2:2 V3:3 V4:4 V5:5 V6:6 V7:7 V8:8 V9:9 V10:10 V11:11 V12:12 }
+of! { V0:0 V1:1 V2:2 V3:3 V4:4 V5:5 V6:6 V7:7 V8:8 V9:9 V10:10 V11:11 V12:12 V13:13 }
+of! { V0:0 V1:1 V2:2 V3:3 V4:4 V5:5 V6:6 V7:7 V8:8 V9:9 V10:10 V11:11 V12:12 V13:13 V14:14 }
+of! { V0:0 V1:1 V2:2 V3:3 V4:4 V5:5 V6:6 V7:7 V8:8 V9:9 V10:10 V11:11 V12:12 V13:13 V14:14 V15:15 }
@@ -0,0 +1,152 @@
+use crate::*;
+use std::fmt::Debug;
+
+/// Attaches a custom error code and message to a rule.
+///
+/// In `#[check(...)]`, follow a rule with `code = "..."` and/or `message = "..."`
+/// instead, which sets [`FieldError::code`](errors::FieldError::code) and
+/// [`FieldError::message`](errors::FieldError::message).
+///
+/// ## Example
+///
+/// ```rust
+/// # use validex::*;
+/// let rule = Custom::new(Range(13..))
+///     .code("too_young")
+///     .message("You must be at least 13 years old");
+///
+/// let err = rule.check(&12).unwrap_err();
+/// assert_eq!(err.code, Some("too_young"));
+/// assert_eq!(err.to_string(), "You must be at least 13 years old");
+///
+/// assert!(Not(rule).verify(&12));
+/// ```
+pub struct Custom<V> {
+    /// The wrapped rule.
+    pub rule: V,
+    /// The error code, if any.
+    pub code: Option<&'static str>,
+    /// The error message, if any.
+    pub message: Option<&'static str>,
+}
+
+impl<V> Custom<V> {
+    /// Wraps a rule without a custom code or message.
+    pub fn new(rule: V) -> Self {
+        Custom {
+            rule,
+            code: None,
+            message: None,
+        }
+    }
+
+    /// Sets the error code.
+    pub fn code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Sets the error message.
+    pub fn message(mut self, message: &'static str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    fn error<E>(&self, error: E) -> errors::CustomError<E> {
+        errors::CustomError {
+            code: self.code,
+            message: self.message,
+            error,
+        }
+    }
+}
+
+impl<T, V> Verify<T> for Custom<V>
+where
+    V: Verify<T>,
+{
+    type Error = errors::CustomError<V::Error>;
+    #[inline]
+    fn verify(&self, val: T) -> bool {
+        self.rule.verify(val)
+    }
+    #[inline]
+    fn error(&self, val: T) -> Self::Error {
+        self.error(self.rule.error(val))
+    }
+    #[inline]
+    fn try_verify(&self, val: T) -> Result<(), Self::Error>
+    where
+        T: Copy,
+    {
+        self.rule.try_verify(val).map_err(|err| self.error(err))
+    }
+}
+
+impl<T, V> Check<T> for Custom<V>
+where
+    V: Check<T>,
+{
+    type Error = errors::CustomError<V::Error>;
+    #[inline]
+    fn check(&self, val: T) -> Result<(), Self::Error> {
+        self.rule.check(val).map_err(|err| self.error(err))
+    }
+}
+
+/// Turns a boolean predicate into a rule, failing with the given message.
+///
+/// Unlike a function returning a `Result`, it implements [`Verify`], so it can be
+/// combined with [`Not`], [`All`] and [`Any`]. The message is a template, where
+/// `{value}` is replaced by the checked value.
+///
+/// ## Example
+///
+/// ```rust
+/// # use validex::*;
+/// fn is_even(n: &u32) -> bool {
+///     n.is_multiple_of(2)
+/// }
+///
+/// #[derive(Check)]
+/// struct Input {
+///   #[check(Any((Pred(is_even, "{value} is odd"), Range(100..))))]
+///   id: u32,
+/// }
+///
+/// assert!(Not(Pred(is_even, "{value} is even")).verify(&3));
+///
+/// let err = Pred(is_even, "{value} is odd").check(&3).unwrap_err();
+/// assert_eq!(err.to_string(), "3 is odd");
+/// ```
+pub struct Pred<F>(pub F, pub &'static str);
+
+impl<'a, F, T> Verify<&'a T> for Pred<F>
+where
+    T: ?Sized + Debug,
+    F: Fn(&T) -> bool,
+{
+    type Error = errors::PredicateError<&'a T>;
+    #[inline]
+    fn verify(&self, val: &'a T) -> bool {
+        (self.0)(val)
+    }
+    #[inline]
+    fn error(&self, value: &'a T) -> Self::Error {
+        errors::PredicateError {
+            value,
+            message: self.1,
+        }
+    }
+}
+
+impl<'a, F, T> Check<&'a T> for Pred<F>
+where
+    T: ?Sized + Debug,
+    F: Fn(&T) -> bool,
+{
+    type Error = errors::PredicateError<&'a T>;
+    fn check(&self, val: &'a T) -> Result<(), Self::Error> {
+        check(self, val)
+    }
+}
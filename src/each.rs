@@ -0,0 +1,222 @@
+use crate::*;
+
+/// Checks every element of a collection, stopping at the first failure.
+///
+/// Works with any collection whose reference iterates over its elements,
+/// such as `[T; N]`, `[T]`, `Vec`, `VecDeque`, `HashSet` and `BTreeSet`.
+/// The error reports the index of the failing element, which is also added
+/// to the path of the field, e.g. `tags[2]`. As a [`Verify`] rule, the error is
+/// wrapped in a [`CheckedError`](errors::CheckedError), which is `Passed` when
+/// negated by [`Not`] and every element passes.
+///
+/// ## Example
+///
+/// ```rust
+/// # use validex::*;
+/// #[derive(Check)]
+/// struct Input {
+///   #[check(Each(Length(1..=16)))]
+///   tags: Vec<String>,
+///   #[check(Maybe(Each(Range(1..=5))))]
+///   ratings: Option<[u8; 3]>,
+/// }
+///
+/// # fn main() {
+/// let input = Input { tags: vec!["rust".into(), "".into()], ratings: None };
+/// let err = input.check().unwrap_err();
+/// assert_eq!(err.path.to_string(), "tags[1]");
+/// # }
+/// ```
+pub struct Each<V>(pub V);
+
+/// Checks every element of a collection, collecting all failures.
+///
+/// Same as [`Each`], but the error lists every failing element with its index.
+///
+/// ## Example
+///
+/// ```rust
+/// # use validex::*;
+/// let scores = vec![0, 3, 9];
+/// let err = EachAll(Range(1..=5)).check(&scores).unwrap_err();
+/// let indices: Vec<_> = err.0.iter().map(|err| err.index).collect();
+/// assert_eq!(indices, [0, 2]);
+/// ```
+pub struct EachAll<V>(pub V);
+
+impl<'a, V, C, T> Verify<&'a C> for Each<V>
+where
+    C: ?Sized,
+    T: ?Sized + 'a,
+    &'a C: IntoIterator<Item = &'a T>,
+    V: Verify<&'a T>,
+{
+    type Error = errors::CheckedError<errors::ElementError<V::Error>>;
+    #[inline]
+    fn verify(&self, val: &'a C) -> bool {
+        val.into_iter().all(|val| self.0.verify(val))
+    }
+    fn error(&self, val: &'a C) -> Self::Error {
+        self.try_verify(val)
+            .err()
+            .unwrap_or(errors::CheckedError::Passed("each"))
+    }
+    fn try_verify(&self, val: &'a C) -> Result<(), Self::Error> {
+        for (index, val) in val.into_iter().enumerate() {
+            self.0.try_verify(val).map_err(|error| {
+                errors::CheckedError::Failed(errors::ElementError { index, error })
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, V, C, T> Check<&'a C> for Each<V>
+where
+    C: ?Sized,
+    T: ?Sized + 'a,
+    &'a C: IntoIterator<Item = &'a T>,
+    V: Check<&'a T>,
+{
+    type Error = errors::ElementError<V::Error>;
+    fn check(&self, val: &'a C) -> Result<(), Self::Error> {
+        for (index, val) in val.into_iter().enumerate() {
+            self.0
+                .check(val)
+                .map_err(|error| errors::ElementError { index, error })?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, V, C, T> Check<&'a C> for EachAll<V>
+where
+    C: ?Sized,
+    T: ?Sized + 'a,
+    &'a C: IntoIterator<Item = &'a T>,
+    V: Check<&'a T>,
+{
+    type Error = errors::ElementErrors<V::Error>;
+    fn check(&self, val: &'a C) -> Result<(), Self::Error> {
+        let errors: Vec<_> = val
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, val)| {
+                let error = self.0.check(val).err()?;
+                Some(errors::ElementError { index, error })
+            })
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors::ElementErrors(errors))
+        }
+    }
+}
+
+/// Checks every key of a map, stopping at the first failure.
+///
+/// Works with any map whose reference iterates over `(&K, &V)` pairs, such as
+/// `BTreeMap` and `HashMap`. The failing key is added to the path of the field,
+/// e.g. `endpoints["api"]`.
+///
+/// ## Example
+///
+/// ```rust
+/// # use validex::*;
+/// # use std::collections::BTreeMap;
+/// #[derive(Check)]
+/// struct Config {
+///   #[check(Keys(Length(1..=16)), Values(Range(1..=10)))]
+///   limits: BTreeMap<String, u32>,
+/// }
+///
+/// # fn main() {
+/// let config = Config { limits: BTreeMap::from([("api".into(), 42)]) };
+/// let err = config.check().unwrap_err();
+/// assert_eq!(err.path.to_string(), r#"limits["api"]"#);
+/// # }
+/// ```
+pub struct Keys<V>(pub V);
+
+/// Checks every value of a map, stopping at the first failure.
+///
+/// See [`Keys`] for the supported maps and the error path.
+pub struct Values<V>(pub V);
+
+/// Checks every `(key, value)` pair of a map, stopping at the first failure.
+///
+/// See [`Keys`] for the supported maps and the error path.
+///
+/// ## Example
+///
+/// ```rust
+/// # use validex::*;
+/// # use std::collections::HashMap;
+/// fn port_in_name((name, port): (&String, &u16)) -> Result<(), &'static str> {
+///     if !name.ends_with(&port.to_string()) {
+///         return Err("name must end with the port");
+///     }
+///     Ok(())
+/// }
+///
+/// let ports = HashMap::from([("http-80".to_string(), 8080)]);
+/// let err = Entries(port_in_name).check(&ports).unwrap_err();
+/// assert_eq!(err.key, "http-80");
+/// ```
+pub struct Entries<V>(pub V);
+
+macro_rules! map {
+    [$($code:literal: $rule:ident => |$entry:ident| $arg:expr => $ty:ty;)*] => [$(
+        impl<'a, V, C, K, T> Verify<&'a C> for $rule<V>
+        where
+            C: ?Sized,
+            K: 'a,
+            T: 'a,
+            &'a C: IntoIterator<Item = (&'a K, &'a T)>,
+            V: Verify<$ty>,
+        {
+            type Error = errors::CheckedError<errors::EntryError<&'a K, V::Error>>;
+            #[inline]
+            fn verify(&self, val: &'a C) -> bool {
+                val.into_iter().all(|$entry| self.0.verify($arg))
+            }
+            fn error(&self, val: &'a C) -> Self::Error {
+                self.try_verify(val).err().unwrap_or(errors::CheckedError::Passed($code))
+            }
+            fn try_verify(&self, val: &'a C) -> Result<(), Self::Error> {
+                for $entry in val {
+                    self.0.try_verify($arg).map_err(|error| {
+                        errors::CheckedError::Failed(errors::EntryError { key: $entry.0, error })
+                    })?;
+                }
+                Ok(())
+            }
+        }
+
+        impl<'a, V, C, K, T> Check<&'a C> for $rule<V>
+        where
+            C: ?Sized,
+            K: 'a,
+            T: 'a,
+            &'a C: IntoIterator<Item = (&'a K, &'a T)>,
+            V: Check<$ty>,
+        {
+            type Error = errors::EntryError<&'a K, V::Error>;
+            fn check(&self, val: &'a C) -> Result<(), Self::Error> {
+                for $entry in val {
+                    self.0
+                        .check($arg)
+                        .map_err(|error| errors::EntryError { key: $entry.0, error })?;
+                }
+                Ok(())
+            }
+        }
+    )*];
+}
+
+map! {
+    "keys": Keys => |entry| entry.0 => &'a K;
+    "values": Values => |entry| entry.1 => &'a T;
+    "entries": Entries => |entry| entry => (&'a K, &'a T);
+}
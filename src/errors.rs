@@ -1,9 +1,302 @@
 use crate::DynError;
 use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    convert::Infallible,
     error::Error,
     fmt::{self, Debug, Display, Formatter},
+    ops::{Bound, Deref, RangeBounds},
 };
 
+/// Exposes the error code and named parameters of an error, used to render
+/// message templates and [translations](crate::i18n).
+///
+/// Built-in errors expose the following codes and parameters:
+///
+/// - [`RangeError`]: `range`, with `value`, `min`, `max`, `min_inclusive`, `max_inclusive`
+/// - [`LengthError`]: `length`, with `len`, `unit`, and the inclusive `min`, `max`
+/// - [`EquelError`]: `equal`, with `value`, `expected`
+/// - [`Unexpected`]: `not`, with the parameters of the negated error
+/// - [`Errors`]: `any`, without parameters
+/// - [`CheckedError`]: the code and parameters of the failed rule
+/// - [`ElementError`]: the code of the element error, with `index` and its parameters
+/// - [`ElementErrors`]: `each`, without parameters
+/// - [`EntryError`]: the code of the entry error, with `key` and its parameters
+/// - [`EmailError`]: `email`, with `value`, `reason`
+/// - [`UrlError`]: `url`, with `value`, `reason`
+/// - [`HostnameError`]: `hostname` or `domain`, with `value`, `reason`
+/// - [`IpError`]: `ip`, `ipv4` or `ipv6`, with `value`
+/// - `PatternError`: `pattern`, with `value`, `pattern`
+/// - [`CharError`]: `ascii`, `alphanumeric`, `chars`, `lowercase`, `uppercase`, `control`
+///   or `trimmed`, with `value`, `char`, `offset`, `index`
+/// - [`ContentError`]: `contains`, `starts_with` or `ends_with`, with `value`, `pattern`
+/// - [`BlankError`]: `blank`, with `value`
+/// - [`MembershipError`]: `in`, with `value`, `set`, or `not_in`, with `value`
+/// - [`PredicateError`]: `predicate`, with `value`
+/// - [`AnyErrors`]: `any`, without parameters
+/// - [`CountError`]: `at_least`, `at_most`, `exactly_one` or `xor`, with `passed`, `min`, `max`
+/// - [`NestedError`]: the code and parameters of the nested field error
+///
+/// Error types of custom rules can rely on the default implementation,
+/// which exposes nothing.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// #[derive(Check)]
+/// struct Signup {
+///   #[check(Length(3..=32), message = "{field} must be between {min} and {max} characters")]
+///   username: String,
+/// }
+///
+/// # fn main() {
+/// let signup = Signup { username: "al".into() };
+/// let err = signup.check().unwrap_err();
+/// assert_eq!(err.to_string(), "username -> username must be between 3 and 32 characters");
+/// # }
+/// ```
+pub trait Params {
+    /// A stable, machine readable code identifying the kind of error.
+    fn code(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Calls `f` with the name and value of each parameter.
+    ///
+    /// Values are typed from their `Debug` form as a [`ParamValue`], so strings
+    /// and characters are rendered without their quotes.
+    fn params(&self, _f: &mut dyn FnMut(&'static str, &dyn Debug)) {}
+
+    /// Calls `f` with each nested error, such as the failed alternatives of [`Any`](crate::Any).
+    fn children(&self, _f: &mut dyn FnMut(&dyn ValidationError)) {}
+
+    /// Calls `f` with each segment locating the failure inside the checked value,
+    /// such as the index of the failing element of [`Each`](crate::Each).
+    fn path(&self, _f: &mut dyn FnMut(Segment)) {}
+}
+
+/// An [`Error`] exposing its [`Params`], as produced by the conditional combinators.
+pub trait ValidationError: Error + Params + Send + Sync {}
+impl<E: ?Sized + Error + Params + Send + Sync> ValidationError for E {}
+
+impl Error for Box<dyn ValidationError + '_> {}
+impl Params for Box<dyn ValidationError + '_> {
+    fn code(&self) -> Option<&'static str> {
+        (**self).code()
+    }
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        (**self).params(f)
+    }
+    fn children(&self, f: &mut dyn FnMut(&dyn ValidationError)) {
+        (**self).children(f)
+    }
+    fn path(&self, f: &mut dyn FnMut(Segment)) {
+        (**self).path(f)
+    }
+}
+
+/// Renders a message template, replacing each `{name}` placeholder with the
+/// matching parameter of `params`. Unknown placeholders are kept as is.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::{*, errors::render};
+/// let err = Length(..=3).check(&vec![1, 2, 3, 4]).unwrap_err();
+/// assert_eq!(render("{len} is longer than {max}", &err), "4 is longer than 3");
+/// ```
+pub fn render(template: &str, params: &dyn Params) -> String {
+    let mut values = Vec::new();
+    params.params(&mut |name, value| values.push((name, ParamValue::new(value))));
+    render_with(template, |name| {
+        values
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.to_string())
+    })
+}
+
+/// Renders a message template like [`render`], where `{field}` refers to `path`.
+pub(crate) fn render_field(template: &str, path: &Path, params: &dyn Params) -> String {
+    let mut values = Vec::new();
+    params.params(&mut |name, value| values.push((name, ParamValue::new(value))));
+    render_with(template, |name| match name {
+        "field" => Some(path.to_string()),
+        _ => values
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.to_string()),
+    })
+}
+
+/// The value of a parameter, typed from its `Debug` form.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::errors::ParamValue;
+/// assert_eq!(ParamValue::new(&42u8), ParamValue::Int(42));
+/// assert_eq!(ParamValue::new(&"al"), ParamValue::String("al".into()));
+/// assert_eq!(ParamValue::new(&"al").to_string(), "al");
+/// assert_eq!(ParamValue::new(&"\"é\"\n").to_string(), "\"é\"\n");
+/// assert_eq!(ParamValue::new(&'\u{301}').to_string(), "\u{301}");
+/// assert_eq!(ParamValue::new(&(1..=5)).to_string(), "1..=5");
+/// ```
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum ParamValue {
+    /// A `bool`.
+    Bool(bool),
+    /// An integer that fits in an `i64`.
+    Int(i64),
+    /// An integer larger than [`i64::MAX`].
+    UInt(u64),
+    /// A finite floating point number.
+    Float(f64),
+    /// A string or character without its quotes, or the `Debug` form of any other value.
+    String(String),
+}
+impl ParamValue {
+    /// Types a parameter from its `Debug` form.
+    pub fn new(value: &dyn Debug) -> ParamValue {
+        let text = format!("{value:?}");
+        if let Ok(value) = text.parse() {
+            return ParamValue::Bool(value);
+        }
+        if let Ok(value) = text.parse() {
+            return ParamValue::Int(value);
+        }
+        if let Ok(value) = text.parse() {
+            return ParamValue::UInt(value);
+        }
+        if let Ok(value) = text.parse::<f64>()
+            && value.is_finite()
+        {
+            return ParamValue::Float(value);
+        }
+        ParamValue::String(unquote(&text).unwrap_or(text))
+    }
+}
+impl Debug for ParamValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamValue::Bool(value) => Debug::fmt(value, f),
+            ParamValue::Int(value) => Debug::fmt(value, f),
+            ParamValue::UInt(value) => Debug::fmt(value, f),
+            ParamValue::Float(value) => Debug::fmt(value, f),
+            ParamValue::String(value) => Debug::fmt(value, f),
+        }
+    }
+}
+impl Display for ParamValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamValue::Bool(value) => Display::fmt(value, f),
+            ParamValue::Int(value) => Display::fmt(value, f),
+            ParamValue::UInt(value) => Display::fmt(value, f),
+            ParamValue::Float(value) => Debug::fmt(value, f),
+            ParamValue::String(value) => f.write_str(value),
+        }
+    }
+}
+
+/// Reverses the `Debug` escaping of a quoted string or character.
+fn unquote(text: &str) -> Option<String> {
+    let inner = text
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .or_else(|| {
+            text.strip_prefix('\'')
+                .and_then(|rest| rest.strip_suffix('\''))
+        })?;
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => out.push('\n'),
+            'r' => out.push('\r'),
+            't' => out.push('\t'),
+            '0' => out.push('\0'),
+            'u' => {
+                let hex = chars.as_str().strip_prefix('{')?;
+                let end = hex.find('}')?;
+                out.push(char::from_u32(u32::from_str_radix(&hex[..end], 16).ok()?)?);
+                chars = hex[end + 1..].chars();
+            }
+            c => out.push(c),
+        }
+    }
+    Some(out)
+}
+
+fn render_with(template: &str, param: impl Fn(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let value = rest
+            .find('}')
+            .and_then(|end| Some((end, param(&rest[1..end])?)));
+        match value {
+            Some((end, value)) => {
+                out.push_str(&value);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn bounds<T: ?Sized + Debug>(
+    range: &impl RangeBounds<T>,
+    f: &mut dyn FnMut(&'static str, &dyn Debug),
+) {
+    if let Bound::Included(min) | Bound::Excluded(min) = range.start_bound() {
+        f("min", &min);
+        f(
+            "min_inclusive",
+            &matches!(range.start_bound(), Bound::Included(_)),
+        );
+    }
+    if let Bound::Included(max) | Bound::Excluded(max) = range.end_bound() {
+        f("max", &max);
+        f(
+            "max_inclusive",
+            &matches!(range.end_bound(), Bound::Included(_)),
+        );
+    }
+}
+
+fn len_bounds(range: &impl RangeBounds<usize>, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+    let min = match range.start_bound() {
+        Bound::Included(&min) => Some(min),
+        Bound::Excluded(&min) => min.checked_add(1),
+        Bound::Unbounded => None,
+    };
+    let max = match range.end_bound() {
+        Bound::Included(&max) => Some(max),
+        Bound::Excluded(&max) => max.checked_sub(1),
+        Bound::Unbounded => None,
+    };
+    if let Some(min) = min {
+        f("min", &min);
+    }
+    if let Some(max) = max {
+        f("max", &max);
+    }
+}
+
 /// Error when a value is out of [`Range`](crate::Range)
 #[derive(Debug)]
 pub struct RangeError<T, R> {
@@ -14,6 +307,16 @@ pub struct RangeError<T, R> {
 }
 
 impl<T: Debug, R: Debug> Error for RangeError<T, R> {}
+impl<T: ?Sized + Debug, R: RangeBounds<T>> Params for RangeError<&T, R> {
+    fn code(&self) -> Option<&'static str> {
+        Some("range")
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        f("value", &self.value);
+        bounds(&self.range, f);
+    }
+}
 impl<T: Debug, R: Debug> Display for RangeError<T, R> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "expected {:?} in {:?}", self.value, self.range)
@@ -41,6 +344,16 @@ impl<T: Debug, R: Debug> Display for RangeError<T, R> {
 #[derive(Debug)]
 pub struct EquelError<T, B>(pub T, pub B);
 impl<A: Debug, B: Debug> Error for EquelError<A, B> {}
+impl<A: Debug, B: Debug> Params for EquelError<A, B> {
+    fn code(&self) -> Option<&'static str> {
+        Some("equal")
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        f("value", &self.0);
+        f("expected", &self.1);
+    }
+}
 impl<A: Debug, B: Debug> Display for EquelError<A, B> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         if f.alternate() {
@@ -55,6 +368,18 @@ impl<A: Debug, B: Debug> Display for EquelError<A, B> {
 #[derive(Debug)]
 pub struct Unexpected<E>(pub E);
 impl<E: Display + Debug> Error for Unexpected<E> {}
+impl<E: Params> Params for Unexpected<E> {
+    fn children(&self, f: &mut dyn FnMut(&dyn ValidationError)) {
+        self.0.children(f);
+    }
+    fn code(&self) -> Option<&'static str> {
+        Some("not")
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        self.0.params(f);
+    }
+}
 impl<E: Display> Display for Unexpected<E> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.write_str("Not: ")?;
@@ -62,53 +387,901 @@ impl<E: Display> Display for Unexpected<E> {
     }
 }
 
-/// Error for input length outside [`Length`](crate::Length) range.
+/// Error of a [`Verify`](crate::Verify) rule that reports a passing value
+/// as [`CheckedError::Passed`] rather than inventing a failure, e.g. when it is
+/// negated by [`Not`](crate::Not).
+///
+/// It is the error of [`Checked`](crate::Checked), and of rules such as
+/// [`Each`](crate::Each), [`Email`](crate::Email), [`Ascii`](crate::Ascii) or
+/// [`AllOf`](crate::AllOf) when used as [`Verify`](crate::Verify) rules.
+/// Their [`Check`](crate::Check) error is the one held by [`CheckedError::Failed`].
+/// A passing rule keeps its code, which is reported with a `rule` parameter,
+/// so a message or translation can name the rule that unexpectedly matched.
+#[derive(Debug)]
+pub enum CheckedError<E> {
+    /// The rule failed with this error.
+    Failed(E),
+    /// The rule with this code passed, reported when it is negated by [`Not`](crate::Not).
+    Passed(&'static str),
+}
+impl<E: Display + Debug> Error for CheckedError<E> {}
+impl<E: Params> Params for CheckedError<E> {
+    fn children(&self, f: &mut dyn FnMut(&dyn ValidationError)) {
+        if let CheckedError::Failed(err) = self {
+            err.children(f);
+        }
+    }
+    fn code(&self) -> Option<&'static str> {
+        match self {
+            CheckedError::Failed(err) => err.code(),
+            CheckedError::Passed(code) => Some(code),
+        }
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        match self {
+            CheckedError::Failed(err) => err.params(f),
+            CheckedError::Passed(code) => f("rule", code),
+        }
+    }
+    fn path(&self, f: &mut dyn FnMut(Segment)) {
+        if let CheckedError::Failed(err) = self {
+            err.path(f);
+        }
+    }
+}
+impl<E: Display> Display for CheckedError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckedError::Failed(err) => Display::fmt(err, f),
+            CheckedError::Passed(code) => write!(f, "expected {code} to fail"),
+        }
+    }
+}
+
+/// Error for input length outside [`Length`](crate::Length) range,
+/// or the range of one of the unicode aware length rules.
 #[derive(Debug)]
 pub struct LengthError<R> {
     /// The actual length of the input.
     pub len: usize,
     /// The expected range for the length.
     pub range: R,
+    /// The unit in which `len` was measured.
+    pub unit: LengthUnit,
 }
 impl<R: Debug> Error for LengthError<R> {}
+impl<R: RangeBounds<usize>> Params for LengthError<R> {
+    fn code(&self) -> Option<&'static str> {
+        Some("length")
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        f("len", &self.len);
+        f("unit", &format_args!("{}", self.unit.as_str()));
+        len_bounds(&self.range, f);
+    }
+}
 impl<R: Debug> Display for LengthError<R> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "expected length {} in {:?}", self.len, self.range)
+        match self.unit {
+            LengthUnit::Len => write!(f, "expected length {} in {:?}", self.len, self.range),
+            unit => write!(
+                f,
+                "expected length {} {} in {:?}",
+                self.len,
+                unit.as_str(),
+                self.range
+            ),
+        }
+    }
+}
+
+/// The unit measured by a length rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthUnit {
+    /// The `len()` of the input: bytes for strings, elements for collections.
+    Len,
+    /// Unicode scalar values, see [`CharLength`](crate::CharLength).
+    Chars,
+    /// Extended grapheme clusters, see `GraphemeLength`.
+    Graphemes,
+    /// UTF-16 code units, see [`Utf16Length`](crate::Utf16Length).
+    Utf16,
+}
+impl LengthUnit {
+    /// The name of the unit, exposed as the `unit` parameter of [`LengthError`].
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LengthUnit::Len => "len",
+            LengthUnit::Chars => "chars",
+            LengthUnit::Graphemes => "graphemes",
+            LengthUnit::Utf16 => "utf16",
+        }
+    }
+}
+
+/// Error returned by the [`Email`](crate::Email) rule.
+#[derive(Debug)]
+pub struct EmailError<'a> {
+    /// The invalid email address.
+    pub value: &'a str,
+    /// Why the email address is invalid.
+    pub reason: &'static str,
+}
+impl Error for EmailError<'_> {}
+impl Params for EmailError<'_> {
+    fn code(&self) -> Option<&'static str> {
+        Some("email")
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        f("value", &self.value);
+        f("reason", &format_args!("{}", self.reason));
+    }
+}
+impl Display for EmailError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid email address {:?}: {}", self.value, self.reason)
+    }
+}
+
+/// Error returned by the [`Url`](crate::Url) rule.
+#[derive(Debug)]
+pub struct UrlError<'a> {
+    /// The invalid URL.
+    pub value: &'a str,
+    /// Why the URL is invalid.
+    pub reason: &'static str,
+}
+impl Error for UrlError<'_> {}
+impl Params for UrlError<'_> {
+    fn code(&self) -> Option<&'static str> {
+        Some("url")
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        f("value", &self.value);
+        f("reason", &format_args!("{}", self.reason));
+    }
+}
+impl Display for UrlError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid URL {:?}: {}", self.value, self.reason)
+    }
+}
+
+/// Error returned by the [`Hostname`](crate::Hostname) and [`DomainName`](crate::DomainName) rules.
+#[derive(Debug)]
+pub struct HostnameError<'a> {
+    /// The invalid name.
+    pub value: &'a str,
+    /// Whether a fully qualified domain name was expected.
+    pub domain: bool,
+    /// Why the name is invalid.
+    pub reason: &'static str,
+}
+impl Error for HostnameError<'_> {}
+impl Params for HostnameError<'_> {
+    fn code(&self) -> Option<&'static str> {
+        Some(if self.domain { "domain" } else { "hostname" })
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        f("value", &self.value);
+        f("reason", &format_args!("{}", self.reason));
+    }
+}
+impl Display for HostnameError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let kind = if self.domain {
+            "domain name"
+        } else {
+            "hostname"
+        };
+        write!(f, "invalid {kind} {:?}: {}", self.value, self.reason)
+    }
+}
+
+/// The IP version expected by [`IpError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpVersion {
+    /// An IPv4 address, see [`Ipv4`](crate::Ipv4).
+    V4,
+    /// An IPv6 address, see [`Ipv6`](crate::Ipv6).
+    V6,
+}
+
+/// Error returned by the [`Ip`](crate::Ip), [`Ipv4`](crate::Ipv4) and [`Ipv6`](crate::Ipv6) rules.
+#[derive(Debug)]
+pub struct IpError<'a> {
+    /// The invalid address.
+    pub value: &'a str,
+    /// The expected IP version, `None` if either is accepted.
+    pub version: Option<IpVersion>,
+}
+impl Error for IpError<'_> {}
+impl Params for IpError<'_> {
+    fn code(&self) -> Option<&'static str> {
+        Some(match self.version {
+            None => "ip",
+            Some(IpVersion::V4) => "ipv4",
+            Some(IpVersion::V6) => "ipv6",
+        })
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        f("value", &self.value);
+    }
+}
+impl Display for IpError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let kind = match self.version {
+            None => "IP",
+            Some(IpVersion::V4) => "IPv4",
+            Some(IpVersion::V6) => "IPv6",
+        };
+        write!(f, "invalid {kind} address {:?}", self.value)
+    }
+}
+
+/// Error returned when a string does not match a [`Pattern`](crate::Pattern).
+#[cfg(feature = "regex")]
+#[derive(Debug)]
+pub struct PatternError<'a> {
+    /// The string that does not match.
+    pub value: &'a str,
+    /// The source of the regular expression.
+    pub pattern: Cow<'static, str>,
+}
+#[cfg(feature = "regex")]
+impl Error for PatternError<'_> {}
+#[cfg(feature = "regex")]
+impl Params for PatternError<'_> {
+    fn code(&self) -> Option<&'static str> {
+        Some("pattern")
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        f("value", &self.value);
+        f("pattern", &self.pattern);
+    }
+}
+#[cfg(feature = "regex")]
+impl Display for PatternError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} does not match `{}`", self.value, self.pattern)
+    }
+}
+
+/// Error pinpointing the offending character of a string, returned by the
+/// character rules such as [`Ascii`](crate::Ascii) and [`Trimmed`](crate::Trimmed).
+#[derive(Debug)]
+pub struct CharError<'a> {
+    /// The code of the rule that failed.
+    pub code: &'static str,
+    /// The checked string.
+    pub value: &'a str,
+    /// The offending character.
+    pub char: char,
+    /// The byte offset of the offending character.
+    pub offset: usize,
+    /// The position of the offending character, counted in `char`s.
+    pub index: usize,
+}
+impl<'a> CharError<'a> {
+    pub(crate) fn new(code: &'static str, value: &'a str, offset: usize, char: char) -> Self {
+        CharError {
+            code,
+            value,
+            char,
+            offset,
+            index: value[..offset].chars().count(),
+        }
+    }
+}
+impl Error for CharError<'_> {}
+impl Params for CharError<'_> {
+    fn code(&self) -> Option<&'static str> {
+        Some(self.code)
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        f("value", &self.value);
+        f("char", &self.char);
+        f("offset", &self.offset);
+        f("index", &self.index);
+    }
+}
+impl Display for CharError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unexpected character {:?} at offset {}",
+            self.char, self.offset
+        )
+    }
+}
+
+/// Error returned by [`Contains`](crate::Contains), [`StartsWith`](crate::StartsWith)
+/// and [`EndsWith`](crate::EndsWith).
+#[derive(Debug)]
+pub struct ContentError<'a, S> {
+    /// The code of the rule that failed.
+    pub code: &'static str,
+    /// The checked string.
+    pub value: &'a str,
+    /// The expected substring, prefix or suffix.
+    pub pattern: S,
+}
+impl<S: Debug> Error for ContentError<'_, S> {}
+impl<S: Debug> Params for ContentError<'_, S> {
+    fn code(&self) -> Option<&'static str> {
+        Some(self.code)
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        f("value", &self.value);
+        f("pattern", &self.pattern);
+    }
+}
+impl<S: Debug> Display for ContentError<'_, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let expected = match self.code {
+            "starts_with" => "to start with",
+            "ends_with" => "to end with",
+            _ => "to contain",
+        };
+        write!(f, "expected {:?} {expected} {:?}", self.value, self.pattern)
+    }
+}
+
+/// Error returned by [`NonBlank`](crate::NonBlank).
+#[derive(Debug)]
+pub struct BlankError<'a> {
+    /// The blank string.
+    pub value: &'a str,
+}
+impl Error for BlankError<'_> {}
+impl Params for BlankError<'_> {
+    fn code(&self) -> Option<&'static str> {
+        Some("blank")
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        f("value", &self.value);
+    }
+}
+impl Display for BlankError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("expected a non-blank string")
+    }
+}
+
+/// Error returned by [`In`](crate::In) and [`NotIn`](crate::NotIn).
+#[derive(Debug)]
+pub struct MembershipError<T> {
+    /// The checked value.
+    pub value: T,
+    /// Whether the value was expected to be in the set.
+    pub expected: bool,
+    /// The expected values, truncated to the first ten, empty for [`NotIn`](crate::NotIn).
+    pub set: String,
+}
+impl<'a, T: ?Sized> MembershipError<&'a T> {
+    pub(crate) fn new(value: &'a T, expected: bool, set: &dyn crate::Set<T>) -> Self {
+        const LIMIT: usize = 10;
+        let mut list = String::new();
+        if expected {
+            let mut count = 0;
+            list.push('[');
+            set.values(&mut |item| {
+                if count < LIMIT {
+                    if count > 0 {
+                        list.push_str(", ");
+                    }
+                    list.push_str(&format!("{item:?}"));
+                }
+                count += 1;
+            });
+            if count > LIMIT {
+                list.push_str(&format!(", … {} more", count - LIMIT));
+            }
+            list.push(']');
+        }
+        MembershipError {
+            value,
+            expected,
+            set: list,
+        }
+    }
+}
+impl<T: Debug> Error for MembershipError<T> {}
+impl<T: Debug> Params for MembershipError<T> {
+    fn code(&self) -> Option<&'static str> {
+        Some(if self.expected { "in" } else { "not_in" })
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        f("value", &self.value);
+        if self.expected {
+            f("set", &format_args!("{}", self.set));
+        }
+    }
+}
+impl<T: Debug> Display for MembershipError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.expected {
+            write!(f, "expected one of {}", self.set)
+        } else {
+            write!(f, "{:?} is not allowed", self.value)
+        }
+    }
+}
+
+/// Error returned when a [`Custom`](crate::Custom) rule fails.
+#[derive(Debug)]
+pub struct CustomError<E> {
+    /// The custom error code, if any.
+    pub code: Option<&'static str>,
+    /// The custom error message, if any.
+    pub message: Option<&'static str>,
+    /// The error of the wrapped rule.
+    pub error: E,
+}
+impl<E: Display + Debug + Params> Error for CustomError<E> {}
+impl<E: Params> Params for CustomError<E> {
+    fn children(&self, f: &mut dyn FnMut(&dyn ValidationError)) {
+        self.error.children(f);
+    }
+    fn code(&self) -> Option<&'static str> {
+        self.code.or_else(|| self.error.code())
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        self.error.params(f);
+    }
+    fn path(&self, f: &mut dyn FnMut(Segment)) {
+        self.error.path(f);
+    }
+}
+impl<E: Display + Params> Display for CustomError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.message {
+            Some(message) => f.write_str(&render(message, &self.error)),
+            None => Display::fmt(&self.error, f),
+        }
+    }
+}
+
+/// Error of the element at `index` returned by [`Each`](crate::Each).
+#[derive(Debug)]
+pub struct ElementError<E> {
+    /// The position of the failing element.
+    pub index: usize,
+    /// The error of the failing element.
+    pub error: E,
+}
+impl<E: Display + Debug> Error for ElementError<E> {}
+impl<E: Params> Params for ElementError<E> {
+    fn children(&self, f: &mut dyn FnMut(&dyn ValidationError)) {
+        self.error.children(f);
+    }
+    fn code(&self) -> Option<&'static str> {
+        self.error.code()
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        f("index", &self.index);
+        self.error.params(f);
+    }
+    fn path(&self, f: &mut dyn FnMut(Segment)) {
+        f(Segment::Index(self.index));
+        self.error.path(f);
+    }
+}
+impl<E: Display> Display for ElementError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+/// Errors of every failing element returned by [`EachAll`](crate::EachAll).
+#[derive(Debug)]
+pub struct ElementErrors<E>(pub Vec<ElementError<E>>);
+impl<E: Display + Debug> Error for ElementErrors<E> {}
+impl<E: ValidationError> Params for ElementErrors<E> {
+    fn code(&self) -> Option<&'static str> {
+        Some("each")
+    }
+    fn children(&self, f: &mut dyn FnMut(&dyn ValidationError)) {
+        for err in &self.0 {
+            f(err);
+        }
+    }
+}
+impl<E: Display> Display for ElementErrors<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, err) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "[{}] {}", err.index, err.error)?;
+        }
+        Ok(())
+    }
+}
+
+/// Error of the map entry at `key` returned by [`Keys`](crate::Keys),
+/// [`Values`](crate::Values) and [`Entries`](crate::Entries).
+#[derive(Debug)]
+pub struct EntryError<K, E> {
+    /// The key of the failing entry.
+    pub key: K,
+    /// The error of the failing entry.
+    pub error: E,
+}
+impl<K: Display + Debug, E: Display + Debug> Error for EntryError<K, E> {}
+impl<K: Display + Debug, E: Params> Params for EntryError<K, E> {
+    fn children(&self, f: &mut dyn FnMut(&dyn ValidationError)) {
+        self.error.children(f);
+    }
+    fn code(&self) -> Option<&'static str> {
+        self.error.code()
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        f("key", &self.key);
+        self.error.params(f);
+    }
+    fn path(&self, f: &mut dyn FnMut(Segment)) {
+        f(Segment::Key(self.key.to_string()));
+        self.error.path(f);
+    }
+}
+impl<K, E: Display> Display for EntryError<K, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+/// Error of a field checked by two or more rules in a typed error enum, or of
+/// an [`AllOf`](crate::AllOf), holding the error of whichever rule failed.
+#[derive(Debug)]
+pub enum Either<A, B> {
+    /// The first rule failed.
+    Left(A),
+    /// One of the remaining rules failed.
+    Right(B),
+}
+impl<A: Display + Debug, B: Display + Debug> Error for Either<A, B> {}
+impl<A: Params, B: Params> Params for Either<A, B> {
+    fn children(&self, f: &mut dyn FnMut(&dyn ValidationError)) {
+        match self {
+            Either::Left(err) => err.children(f),
+            Either::Right(err) => err.children(f),
+        }
+    }
+    fn code(&self) -> Option<&'static str> {
+        match self {
+            Either::Left(err) => err.code(),
+            Either::Right(err) => err.code(),
+        }
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        match self {
+            Either::Left(err) => err.params(f),
+            Either::Right(err) => err.params(f),
+        }
+    }
+    fn path(&self, f: &mut dyn FnMut(Segment)) {
+        match self {
+            Either::Left(err) => err.path(f),
+            Either::Right(err) => err.path(f),
+        }
+    }
+}
+impl<A: Display, B: Display> Display for Either<A, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Either::Left(err) => Display::fmt(err, f),
+            Either::Right(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+/// The errors of every sub-rule of an [`AnyOf`](crate::AnyOf), as a tuple.
+pub struct AnyErrors<E>(pub E);
+
+macro_rules! any_errors {
+    [$($ty:tt: $idx:tt)*] => [
+        impl<$($ty: Display + Debug),*> Error for AnyErrors<($($ty,)*)> {}
+        impl<$($ty: Debug),*> Debug for AnyErrors<($($ty,)*)> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("AnyErrors")$(.field(&self.0.$idx))*.finish()
+            }
+        }
+        impl<$($ty: ValidationError),*> Params for AnyErrors<($($ty,)*)> {
+            fn code(&self) -> Option<&'static str> {
+                Some("any")
+            }
+            fn children(&self, f: &mut dyn FnMut(&dyn ValidationError)) {
+                $(f(&self.0.$idx);)*
+            }
+        }
+        impl<$($ty: Display),*> Display for AnyErrors<($($ty,)*)> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                $(
+                    Display::fmt(&self.0.$idx, f)?;
+                    f.write_str("; ")?;
+                )*
+                Ok(())
+            }
+        }
+    ]
+}
+
+any_errors! { E0:0 }
+any_errors! { E0:0 E1:1 }
+any_errors! { E0:0 E1:1 E2:2 }
+any_errors! { E0:0 E1:1 E2:2 E3:3 }
+any_errors! { E0:0 E1:1 E2:2 E3:3 E4:4 }
+any_errors! { E0:0 E1:1 E2:2 E3:3 E4:4 E5:5 }
+any_errors! { E0:0 E1:1 E2:2 E3:3 E4:4 E5:5 E6:6 }
+any_errors! { E0:0 E1:1 E2:2 E3:3 E4:4 E5:5 E6:6 E7:7 }
+any_errors! { E0:0 E1:1 E2:2 E3:3 E4:4 E5:5 E6:6 E7:7 E8:8 }
+any_errors! { E0:0 E1:1 E2:2 E3:3 E4:4 E5:5 E6:6 E7:7 E8:8 E9:9 }
+any_errors! { E0:0 E1:1 E2:2 E3:3 E4:4 E5:5 E6:6 E7:7 E8:8 E9:9 E10:10 }
+any_errors! { E0:0 E1:1 E2:2 E3:3 E4:4 E5:5 E6:6 E7:7 E8:8 E9:9 E10:10 E11:11 }
+any_errors! { E0:0 E1:1 E2:2 E3:3 E4:4 E5:5 E6:6 E7:7 E8:8 E9:9 E10:10 E11:11 E12:12 }
+any_errors! { E0:0 E1:1 E2:2 E3:3 E4:4 E5:5 E6:6 E7:7 E8:8 E9:9 E10:10 E11:11 E12:12 E13:13 }
+any_errors! { E0:0 E1:1 E2:2 E3:3 E4:4 E5:5 E6:6 E7:7 E8:8 E9:9 E10:10 E11:11 E12:12 E13:13 E14:14 }
+any_errors! { E0:0 E1:1 E2:2 E3:3 E4:4 E5:5 E6:6 E7:7 E8:8 E9:9 E10:10 E11:11 E12:12 E13:13 E14:14 E15:15 }
+
+/// Error returned when a [`Pred`](crate::Pred) predicate fails.
+#[derive(Debug)]
+pub struct PredicateError<T> {
+    /// The checked value.
+    pub value: T,
+    /// The message template of the predicate.
+    pub message: &'static str,
+}
+impl<T: Debug> Error for PredicateError<T> {}
+impl<T: Debug> Params for PredicateError<T> {
+    fn code(&self) -> Option<&'static str> {
+        Some("predicate")
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        f("value", &self.value);
+    }
+}
+impl<T: Debug> Display for PredicateError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&render(self.message, self))
+    }
+}
+
+/// A single step in a [`Path`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment {
+    /// A named field of a struct or enum variant.
+    Field(&'static str),
+    /// A positional field of a tuple struct or tuple variant.
+    Position(usize),
+    /// An enum variant.
+    Variant(&'static str),
+    /// An element index of a collection.
+    Index(usize),
+    /// A key of a map.
+    Key(String),
+}
+
+impl From<&'static str> for Segment {
+    fn from(key: &'static str) -> Self {
+        Segment::Field(key)
+    }
+}
+
+/// The location of a failing value, from the outermost field inwards.
+///
+/// An empty path refers to the validated value itself, e.g. for container rules.
+///
+/// Displays as a dotted path (`data.id`, `items[2].name`), or as a
+/// [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901) with [`Path::to_pointer`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Path(pub Vec<Segment>);
+
+impl Path {
+    /// Formats the path as a JSON Pointer, e.g. `/data/id`.
+    pub fn to_pointer(&self) -> String {
+        let mut pointer = String::new();
+        for segment in &self.0 {
+            pointer.push('/');
+            match segment {
+                Segment::Field(name) | Segment::Variant(name) => pointer.push_str(name),
+                Segment::Position(idx) | Segment::Index(idx) => pointer.push_str(&idx.to_string()),
+                Segment::Key(key) => pointer.push_str(&key.replace('~', "~0").replace('/', "~1")),
+            }
+        }
+        pointer
+    }
+}
+
+impl Display for Path {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                Segment::Field(name) | Segment::Variant(name) => {
+                    if i > 0 {
+                        f.write_str(".")?;
+                    }
+                    f.write_str(name)?;
+                }
+                Segment::Position(idx) => {
+                    if i > 0 {
+                        f.write_str(".")?;
+                    }
+                    Display::fmt(idx, f)?;
+                }
+                Segment::Index(idx) => write!(f, "[{idx}]")?,
+                Segment::Key(key) => write!(f, "[{key:?}]")?,
+            }
+        }
+        Ok(())
     }
 }
 
 /// Reports an error when a field check fails.
 #[derive(Debug)]
 pub struct FieldError<'err> {
-    /// The name of the field that caused the error.
-    pub key: &'static str,
-    /// The underlying error.
-    pub error: DynError<'err>,
+    /// The location of the field that caused the error.
+    pub path: Path,
+    /// The error code given with `code = "..."`, or else the code of the underlying error.
+    pub code: Option<&'static str>,
+    /// The error message given with `message = "..."`, if any.
+    ///
+    /// When present, it is rendered as a template (see [`Params`]) and
+    /// displayed instead of the underlying error. `{field}` refers to the path.
+    pub message: Option<&'static str>,
+    /// The code of the underlying error, identifying the rule that failed.
+    pub kind: Option<&'static str>,
+    /// The underlying error, whose parameters and nested errors are only
+    /// formatted when rendered or [reported](FieldError::report).
+    ///
+    /// Errors that do not implement [`Params`] are kept as a [`DynError`], without parameters.
+    pub error: Box<dyn ValidationError + 'err>,
 }
 impl<'err> FieldError<'err> {
     /// Create a new [`FieldError`] for a given field key and error.
-    pub fn new(key: &'static str, error: impl Into<DynError<'err>>) -> FieldError<'err> {
+    pub fn new(key: impl Into<Segment>, error: impl Into<DynError<'err>>) -> FieldError<'err> {
         FieldError {
-            key,
-            error: error.into(),
+            path: Path(vec![key.into()]),
+            code: None,
+            message: None,
+            kind: None,
+            error: Box::new(Opaque(error.into())),
         }
     }
+
+    /// Renders a message template with the parameters of this error,
+    /// where `{field}` refers to the path.
+    pub fn render(&self, template: &str) -> String {
+        render_field(template, &self.path, &*self.error)
+    }
+
+    /// Returns a structured representation of this error.
+    pub fn report(&self) -> Report {
+        let mut report = Report {
+            path: self.path.to_pointer(),
+            kind: self.kind,
+            code: self.code,
+            message: match self.message {
+                Some(message) => self.render(message),
+                None => self.error.to_string(),
+            },
+            params: Default::default(),
+            children: Vec::new(),
+        };
+        self.error.params(&mut |name, value| {
+            report.params.insert(name, ParamValue::new(value));
+        });
+        self.error
+            .children(&mut |child| report.children.push(Report::new(child)));
+        report
+    }
+
+    /// Prepends the given segments to the path of this error.
+    pub fn prefix(mut self, segments: &[Segment]) -> Self {
+        self.path.0.splice(0..0, segments.iter().cloned());
+        self
+    }
 }
 impl<'err> Error for FieldError<'err> {}
+impl<'err> Params for FieldError<'err> {
+    fn code(&self) -> Option<&'static str> {
+        self.code
+    }
+
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        self.error.params(f);
+    }
+    fn children(&self, f: &mut dyn FnMut(&dyn ValidationError)) {
+        self.error.children(f);
+    }
+}
 impl<'err> Display for FieldError<'err> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        Display::fmt(&self.key, f)?;
-        f.write_str(" -> ")?;
-        Display::fmt(&self.error, f)
+        if !self.path.0.is_empty() {
+            Display::fmt(&self.path, f)?;
+            f.write_str(" -> ")?;
+        }
+        self.fmt_message(f)
+    }
+}
+impl FieldError<'_> {
+    fn fmt_message(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.message {
+            Some(message) => f.write_str(&self.render(message)),
+            None => Display::fmt(&self.error, f),
+        }
+    }
+}
+
+/// Error of a value checked with [`Valid`](crate::Valid).
+///
+/// The path of the nested [`FieldError`] is exposed through [`Params::path`],
+/// so it is merged into the path of the enclosing field, and only its message is displayed.
+#[derive(Debug)]
+pub struct NestedError<'err>(pub FieldError<'err>);
+impl Error for NestedError<'_> {}
+impl Params for NestedError<'_> {
+    fn code(&self) -> Option<&'static str> {
+        self.0.code
+    }
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        self.0.error.params(f);
+    }
+    fn children(&self, f: &mut dyn FnMut(&dyn ValidationError)) {
+        self.0.error.children(f);
+    }
+    fn path(&self, f: &mut dyn FnMut(Segment)) {
+        self.0.path.0.iter().cloned().for_each(f);
+    }
+}
+impl Display for NestedError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt_message(f)
     }
 }
 
 /// A list of errors returned when multiple [`Any`](crate::Any) checks fail.
+///
+/// It is empty when [`Any`](crate::Any) is given an empty list of rules,
+/// and displays as `no rules to satisfy`.
+///
+/// The failed alternatives are kept as [`DynError`]s, so they are reported
+/// as children with their message only, without code or parameters.
+/// [`AnyOf`](crate::AnyOf) keeps the typed errors instead.
 #[derive(Debug)]
 pub struct Errors<'err>(pub Box<[DynError<'err>]>);
 impl<'err> Error for Errors<'err> {}
+impl<'err> Params for Errors<'err> {
+    fn code(&self) -> Option<&'static str> {
+        Some("any")
+    }
+    fn children(&self, f: &mut dyn FnMut(&dyn ValidationError)) {
+        for err in &self.0 {
+            f(&Opaque(&**err));
+        }
+    }
+}
 impl<'err> Display for Errors<'err> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return f.write_str("no rules to satisfy");
+        }
         for err in &self.0 {
             Display::fmt(err, f)?;
             f.write_str("; ")?;
@@ -116,3 +1289,241 @@ impl<'err> Display for Errors<'err> {
         Ok(())
     }
 }
+
+/// Error of the counting combinators [`AtLeast`](crate::AtLeast),
+/// [`AtMost`](crate::AtMost), [`ExactlyOne`](crate::ExactlyOne) and [`Xor`](crate::Xor).
+#[derive(Debug)]
+pub struct CountError<'err> {
+    /// The combinator that failed.
+    pub kind: CountKind,
+    /// How many sub-rules passed.
+    pub passed: usize,
+    /// The minimum number of sub-rules required to pass.
+    pub min: usize,
+    /// The maximum number of sub-rules allowed to pass.
+    pub max: usize,
+    /// The errors of the sub-rules that failed when too few passed,
+    /// empty when too many passed.
+    pub failures: Box<[DynError<'err>]>,
+}
+impl Error for CountError<'_> {}
+impl Params for CountError<'_> {
+    fn code(&self) -> Option<&'static str> {
+        Some(self.kind.as_str())
+    }
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        f("passed", &self.passed);
+        f("min", &self.min);
+        f("max", &self.max);
+    }
+    fn children(&self, f: &mut dyn FnMut(&dyn ValidationError)) {
+        for err in &self.failures {
+            f(&Opaque(&**err));
+        }
+    }
+}
+impl Display for CountError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            CountKind::AtLeast => write!(f, "expected at least {} to pass", rules(self.min))?,
+            CountKind::AtMost => write!(f, "expected at most {} to pass", rules(self.max))?,
+            CountKind::ExactlyOne | CountKind::Xor => {
+                f.write_str("expected exactly one rule to pass")?
+            }
+        }
+        write!(f, ", {} passed", self.passed)?;
+        for (i, err) in self.failures.iter().enumerate() {
+            f.write_str(if i == 0 { ": " } else { "; " })?;
+            Display::fmt(err, f)?;
+        }
+        Ok(())
+    }
+}
+
+fn rules(n: usize) -> String {
+    match n {
+        1 => "1 rule".to_string(),
+        n => format!("{n} rules"),
+    }
+}
+
+/// The counting combinator that produced a [`CountError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CountKind {
+    /// [`AtLeast`](crate::AtLeast)
+    AtLeast,
+    /// [`AtMost`](crate::AtMost)
+    AtMost,
+    /// [`ExactlyOne`](crate::ExactlyOne)
+    ExactlyOne,
+    /// [`Xor`](crate::Xor)
+    Xor,
+}
+impl CountKind {
+    /// The error code of the combinator, exposed by [`CountError`].
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CountKind::AtLeast => "at_least",
+            CountKind::AtMost => "at_most",
+            CountKind::ExactlyOne => "exactly_one",
+            CountKind::Xor => "xor",
+        }
+    }
+}
+
+/// All field errors collected by a derived `check_all()`, in declaration order.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// #[derive(Check)]
+/// struct Input {
+///   #[check(Range(1..=10))]
+///   id: u32,
+///   #[check(Length(3..=5))]
+///   name: String,
+/// }
+///
+/// # fn main() {
+/// let input = Input { id: 42, name: "validex".into() };
+/// let errors = input.check_all().unwrap_err();
+/// assert_eq!(errors.0.len(), 2);
+/// assert_eq!(errors.0[0].path.to_string(), "id");
+/// assert_eq!(errors.0[1].path.to_string(), "name");
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FieldErrors<'err>(pub Vec<FieldError<'err>>);
+impl<'err> Error for FieldErrors<'err> {}
+impl<'err> Params for FieldErrors<'err> {}
+impl<'err> Display for FieldErrors<'err> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, err) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            Display::fmt(err, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'err> FieldErrors<'err> {
+    /// Returns a structured representation of every error.
+    pub fn report(&self) -> Vec<Report> {
+        self.0.iter().map(FieldError::report).collect()
+    }
+}
+impl<'err> IntoIterator for FieldErrors<'err> {
+    type Item = FieldError<'err>;
+    type IntoIter = std::vec::IntoIter<FieldError<'err>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl Params for String {}
+impl Params for &str {}
+impl Params for Cow<'_, str> {}
+impl Params for Infallible {}
+impl Params for DynError<'_> {}
+
+/// Exposes an error without [`Params`], such as a [`DynError`], as a [`ValidationError`].
+#[derive(Debug)]
+pub(crate) struct Opaque<E>(pub(crate) E);
+impl<'err, E> Error for Opaque<E> where E: Debug + Deref<Target = dyn Error + Send + Sync + 'err> {}
+impl<'err, E> Params for Opaque<E> where E: Deref<Target = dyn Error + Send + Sync + 'err> {}
+impl<'err, E> Display for Opaque<E>
+where
+    E: Deref<Target = dyn Error + Send + Sync + 'err>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&*self.0, f)
+    }
+}
+
+/// A structured, owned representation of a validation failure.
+///
+/// With the `serde` feature, it can be serialized, e.g. as a JSON response.
+///
+/// The alternatives of [`Any`](crate::Any) are reported with their message only,
+/// while [`AnyOf`](crate::AnyOf) keeps their codes and parameters.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// #[derive(Check)]
+/// struct Input {
+///   #[check(AnyOf((Range(1..=10), Range(40..=50))))]
+///   id: u32,
+/// }
+///
+/// # fn main() {
+/// let input = Input { id: 12 };
+/// let report = input.check().unwrap_err().report();
+/// assert_eq!(report.path, "/id");
+/// assert_eq!(report.kind, Some("any"));
+/// assert_eq!(report.children[0].kind, Some("range"));
+/// assert_eq!(report.children[0].params["max"], errors::ParamValue::Int(10));
+/// assert_eq!(report.children[1].params["min"], errors::ParamValue::Int(40));
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Report {
+    /// The location of the failing field as a JSON Pointer, relative to the parent for nested errors.
+    pub path: String,
+    /// The code of the error, identifying the rule that failed.
+    pub kind: Option<&'static str>,
+    /// The custom error code, or else the code of the error.
+    pub code: Option<&'static str>,
+    /// The error message.
+    pub message: String,
+    /// The parameters of the error, serialized as JSON numbers, booleans or strings.
+    pub params: BTreeMap<&'static str, ParamValue>,
+    /// The nested errors, such as the failed alternatives of [`Any`](crate::Any).
+    pub children: Vec<Report>,
+}
+impl Report {
+    /// Creates a report of an error that is not attributed to a field.
+    pub fn new(err: &dyn ValidationError) -> Report {
+        let mut path = Path::default();
+        err.path(&mut |segment| path.0.push(segment));
+        let mut report = Report {
+            path: path.to_pointer(),
+            kind: err.code(),
+            code: err.code(),
+            message: err.to_string(),
+            params: BTreeMap::new(),
+            children: Vec::new(),
+        };
+        err.params(&mut |name, value| {
+            report.params.insert(name, ParamValue::new(value));
+        });
+        err.children(&mut |child| report.children.push(Report::new(child)));
+        report
+    }
+}
+impl Error for Report {}
+impl Params for Report {
+    fn code(&self) -> Option<&'static str> {
+        self.code
+    }
+    fn params(&self, f: &mut dyn FnMut(&'static str, &dyn Debug)) {
+        for (name, value) in &self.params {
+            f(name, value);
+        }
+    }
+    fn children(&self, f: &mut dyn FnMut(&dyn ValidationError)) {
+        for child in &self.children {
+            f(child);
+        }
+    }
+}
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
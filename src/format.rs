@@ -0,0 +1,290 @@
+use crate::*;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Checks if a string is a valid email address, such as `user@example.com`.
+///
+/// The local part may contain the characters allowed by RFC 5322 outside of
+/// quotes, as well as non-ASCII characters. The domain must be a valid
+/// [`DomainName`] or an address literal, such as `[192.168.0.1]` or `[IPv6:::1]`.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// #[derive(Check)]
+/// struct Signup {
+///   #[check(Email)]
+///   email: String,
+/// }
+///
+/// assert!(Email.verify("user+tag@example.com"));
+/// assert!(!Email.verify("user@localhost"));
+/// ```
+pub struct Email;
+
+/// Checks if a string is an absolute URL whose scheme is in the given allowlist.
+///
+/// Schemes are compared case-insensitively, an empty allowlist accepts any scheme.
+/// When the URL has an authority (`scheme://...`), its host must be a valid
+/// [`Hostname`] or IP address, and its port a number up to 65535.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// #[derive(Check)]
+/// struct Profile {
+///   #[check(Maybe(Url(&["http", "https"])))]
+///   website: Option<String>,
+/// }
+///
+/// assert!(Url(&["https"]).verify("https://example.com:8080/path?query#top"));
+/// assert!(!Url(&["https"]).verify("ftp://example.com"));
+/// assert!(Url(&[]).verify("mailto:user@example.com"));
+/// ```
+pub struct Url<'a>(pub &'a [&'a str]);
+
+/// Checks if a string is a valid hostname, as defined by RFC 1123.
+///
+/// Labels contain ASCII letters, digits and hyphens, and a trailing dot is allowed.
+/// With the `idna` feature, internationalized names, such as `bücher.example`,
+/// are converted to their ASCII form before being checked; otherwise they are rejected.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// assert!(Hostname.verify("localhost"));
+/// assert!(Hostname.verify("db-1.internal."));
+/// assert!(!Hostname.verify("-db.internal"));
+/// ```
+pub struct Hostname;
+
+/// Checks if a string is a fully qualified domain name.
+///
+/// Same as [`Hostname`], but requires at least two labels and a non-numeric top-level domain.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// assert!(DomainName.verify("example.com"));
+/// assert!(!DomainName.verify("localhost"));
+/// assert!(!DomainName.verify("192.168.0.1"));
+/// ```
+pub struct DomainName;
+
+/// Checks if a string is an IPv4 or IPv6 address.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// assert!(Ip.verify("192.168.0.1"));
+/// assert!(Ip.verify("::1"));
+/// assert!(!Ip.verify("192.168.0.256"));
+/// ```
+pub struct Ip;
+
+/// Checks if a string is an IPv4 address, such as `192.168.0.1`.
+pub struct Ipv4;
+
+/// Checks if a string is an IPv6 address, such as `::1`.
+pub struct Ipv6;
+
+macro_rules! format {
+    [$($code:literal: $rule:ty => $error:ident |$this:ident, $s:ident| $check:expr;)*] => [$(
+        impl<'a, T> Verify<&'a T> for $rule
+        where
+            T: ?Sized + AsRef<str>,
+        {
+            type Error = errors::CheckedError<errors::$error<'a>>;
+            #[inline]
+            fn verify(&self, val: &'a T) -> bool {
+                let ($this, $s) = (self, val.as_ref());
+                $check.is_ok()
+            }
+            fn error(&self, val: &'a T) -> Self::Error {
+                let ($this, $s) = (self, val.as_ref());
+                $check.err().map_or(errors::CheckedError::Passed($code), errors::CheckedError::Failed)
+            }
+            #[inline]
+            fn try_verify(&self, val: &'a T) -> Result<(), Self::Error> {
+                let ($this, $s) = (self, val.as_ref());
+                $check.map_err(errors::CheckedError::Failed)
+            }
+        }
+
+        impl<'a, T> Check<&'a T> for $rule
+        where
+            T: ?Sized + AsRef<str>,
+        {
+            type Error = errors::$error<'a>;
+            #[inline]
+            fn check(&self, val: &'a T) -> Result<(), Self::Error> {
+                let ($this, $s) = (self, val.as_ref());
+                $check
+            }
+        }
+    )*];
+}
+
+format! {
+    "email": Email => EmailError |_this, value| {
+        email(value).map_err(|reason| errors::EmailError { value, reason })
+    };
+    "url": Url<'_> => UrlError |this, value| {
+        url(value, this.0).map_err(|reason| errors::UrlError { value, reason })
+    };
+    "hostname": Hostname => HostnameError |_this, value| {
+        hostname(value, false).map_err(|reason| errors::HostnameError { value, domain: false, reason })
+    };
+    "domain": DomainName => HostnameError |_this, value| {
+        hostname(value, true).map_err(|reason| errors::HostnameError { value, domain: true, reason })
+    };
+    "ip": Ip => IpError |_this, value| ip(value, None);
+    "ipv4": Ipv4 => IpError |_this, value| ip(value, Some(errors::IpVersion::V4));
+    "ipv6": Ipv6 => IpError |_this, value| ip(value, Some(errors::IpVersion::V6));
+}
+
+fn email(s: &str) -> Result<(), &'static str> {
+    if s.len() > 254 {
+        return Err("longer than 254 characters");
+    }
+    let (local, domain) = s.rsplit_once('@').ok_or("missing `@`")?;
+    if local.is_empty() {
+        return Err("missing local part");
+    }
+    if local.len() > 64 {
+        return Err("local part longer than 64 characters");
+    }
+    if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+        return Err("misplaced `.` in local part");
+    }
+    let atext =
+        |c: char| c.is_ascii_alphanumeric() || !c.is_ascii() || "!#$%&'*+-/=?^_`{|}~.".contains(c);
+    if !local.chars().all(atext) {
+        return Err("invalid character in local part");
+    }
+    if let Some(literal) = domain.strip_prefix('[').and_then(|d| d.strip_suffix(']')) {
+        let valid = match literal.strip_prefix("IPv6:") {
+            Some(addr) => addr.parse::<Ipv6Addr>().is_ok(),
+            None => literal.parse::<Ipv4Addr>().is_ok(),
+        };
+        return if valid {
+            Ok(())
+        } else {
+            Err("invalid address literal")
+        };
+    }
+    hostname(domain, true).map_err(|_| "invalid domain")
+}
+
+fn url(s: &str, schemes: &[&str]) -> Result<(), &'static str> {
+    let (scheme, rest) = s.split_once(':').ok_or("missing scheme")?;
+    let mut chars = scheme.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c));
+    if !valid {
+        return Err("invalid scheme");
+    }
+    if !schemes.is_empty() && !schemes.iter().any(|s| s.eq_ignore_ascii_case(scheme)) {
+        return Err("scheme not allowed");
+    }
+    if rest.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err("contains whitespace or control characters");
+    }
+    let Some(rest) = rest.strip_prefix("//") else {
+        return Ok(());
+    };
+    let authority = &rest[..rest.find(['/', '?', '#']).unwrap_or(rest.len())];
+    let host = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    let (host, port) = match host.strip_prefix('[') {
+        Some(literal) => {
+            let (addr, port) = literal.split_once(']').ok_or("invalid host")?;
+            addr.parse::<Ipv6Addr>().map_err(|_| "invalid host")?;
+            match port {
+                "" => (None, None),
+                port => (None, Some(port.strip_prefix(':').ok_or("invalid port")?)),
+            }
+        }
+        None => match host.split_once(':') {
+            Some((host, port)) => (Some(host), Some(port)),
+            None => (Some(host), None),
+        },
+    };
+    if let Some(port) = port
+        && !port.is_empty()
+        && !(port.bytes().all(|b| b.is_ascii_digit()) && port.parse::<u16>().is_ok())
+    {
+        return Err("invalid port");
+    }
+    match host {
+        // An IPv6 literal, already checked.
+        None => Ok(()),
+        Some("") if scheme.eq_ignore_ascii_case("file") => Ok(()),
+        Some("") => Err("missing host"),
+        Some(host) if host.parse::<Ipv4Addr>().is_ok() => Ok(()),
+        Some(host) => hostname(host, false).map_err(|_| "invalid host"),
+    }
+}
+
+fn hostname(s: &str, domain: bool) -> Result<(), &'static str> {
+    #[cfg(feature = "idna")]
+    if !s.is_ascii() {
+        let ascii = idna::domain_to_ascii(s).map_err(|_| "invalid internationalized name")?;
+        return hostname(&ascii, domain);
+    }
+    if s.is_empty() {
+        return Err("empty");
+    }
+    if !s.is_ascii() {
+        return Err("contains non-ASCII characters");
+    }
+    let name = s.strip_suffix('.').unwrap_or(s);
+    if name.len() > 253 {
+        return Err("longer than 253 characters");
+    }
+    let mut labels = 0;
+    let mut last = "";
+    for label in name.split('.') {
+        if label.is_empty() {
+            return Err("empty label");
+        }
+        if label.len() > 63 {
+            return Err("label longer than 63 characters");
+        }
+        if !label
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        {
+            return Err("invalid character");
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err("label starting or ending with `-`");
+        }
+        labels += 1;
+        last = label;
+    }
+    if domain && labels < 2 {
+        return Err("not fully qualified");
+    }
+    if domain && last.bytes().all(|b| b.is_ascii_digit()) {
+        return Err("numeric top-level domain");
+    }
+    Ok(())
+}
+
+fn ip(value: &str, version: Option<errors::IpVersion>) -> Result<(), errors::IpError<'_>> {
+    let valid = match version {
+        None => value.parse::<std::net::IpAddr>().is_ok(),
+        Some(errors::IpVersion::V4) => value.parse::<Ipv4Addr>().is_ok(),
+        Some(errors::IpVersion::V6) => value.parse::<Ipv6Addr>().is_ok(),
+    };
+    if !valid {
+        return Err(errors::IpError { value, version });
+    }
+    Ok(())
+}
@@ -0,0 +1,167 @@
+use crate::errors::{FieldError, FieldErrors, Path, Report, ValidationError, render_field};
+use std::collections::HashMap;
+use std::{fs, io};
+
+/// Looks up message templates by locale and error code.
+///
+/// Templates are rendered with the parameters of the error,
+/// see [`Params`](crate::errors::Params).
+pub trait Translate {
+    /// Returns the message template for `code` in `locale`, if any.
+    fn template(&self, locale: &str, code: &str) -> Option<&str>;
+}
+
+/// A message catalog, mapping error codes to templates per locale.
+///
+/// Catalog sources contain one `code = template` entry per line.
+/// Empty lines and lines starting with `#` are ignored.
+///
+/// A region specific locale, such as `de-AT`, falls back to its language (`de`).
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::{*, i18n::Catalog};
+/// #[derive(Check)]
+/// struct Signup {
+///   #[check(Length(3..=32))]
+///   username: String,
+/// }
+///
+/// # fn main() -> std::io::Result<()> {
+/// let mut catalog = Catalog::new();
+/// catalog.parse("de", "length = {field} muss zwischen {min} und {max} Zeichen lang sein")?;
+/// catalog.parse("bn", "length = {field} {min} থেকে {max} অক্ষরের মধ্যে হতে হবে")?;
+///
+/// let signup = Signup { username: "al".into() };
+/// let err = signup.check().unwrap_err();
+/// assert_eq!(
+///     err.translate(&catalog, "de-AT"),
+///     "username muss zwischen 3 und 32 Zeichen lang sein"
+/// );
+/// assert_eq!(err.translate(&catalog, "en"), "expected length 2 in 3..=32");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Catalog {
+    locales: HashMap<String, HashMap<String, String>>,
+}
+
+impl Catalog {
+    /// Creates an empty catalog.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a template for `code` in `locale`, replacing any previous one.
+    pub fn insert(&mut self, locale: &str, code: &str, template: &str) {
+        self.locales
+            .entry(locale.to_owned())
+            .or_default()
+            .insert(code.to_owned(), template.to_owned());
+    }
+
+    /// Adds every entry of a catalog source to `locale`.
+    pub fn parse(&mut self, locale: &str, source: &str) -> io::Result<()> {
+        for (idx, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((code, template)) = line.split_once('=') else {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: expected `code = template`", idx + 1),
+                ));
+            };
+            self.insert(locale, code.trim(), template.trim());
+        }
+        Ok(())
+    }
+
+    /// Adds every entry of a catalog file to `locale`.
+    pub fn load(&mut self, locale: &str, path: impl AsRef<std::path::Path>) -> io::Result<()> {
+        self.parse(locale, &fs::read_to_string(path)?)
+    }
+}
+
+impl Translate for Catalog {
+    fn template(&self, locale: &str, code: &str) -> Option<&str> {
+        let find = |locale| Some(self.locales.get(locale)?.get(code)?.as_str());
+        find(locale).or_else(|| find(locale.split(['-', '_']).next()?))
+    }
+}
+
+impl FieldError<'_> {
+    /// Renders the message of this error in `locale`.
+    ///
+    /// Without a template for the error code, falls back to the
+    /// `message = "..."` template, then to the underlying error.
+    /// Nested errors are only translated by [`FieldError::translate_report`].
+    pub fn translate(&self, translator: &dyn Translate, locale: &str) -> String {
+        let template = self
+            .code
+            .and_then(|code| translator.template(locale, code))
+            .or(self.message);
+        match template {
+            Some(template) => self.render(template),
+            None => self.error.to_string(),
+        }
+    }
+
+    /// Returns a [report](FieldError::report) of this error, with the messages of the
+    /// error and of every nested error, such as the failed alternatives of
+    /// [`AnyOf`](crate::AnyOf), rendered in `locale`.
+    ///
+    /// Nested errors without a template keep their message. In their templates,
+    /// `{field}` refers to the path of the field, extended with their own path.
+    pub fn translate_report(&self, translator: &dyn Translate, locale: &str) -> Report {
+        let mut report = self.report();
+        report.message = self.translate(translator, locale);
+        translate_children(&mut report, &*self.error, &self.path, translator, locale);
+        report
+    }
+}
+
+fn translate_children(
+    report: &mut Report,
+    err: &dyn ValidationError,
+    path: &Path,
+    translator: &dyn Translate,
+    locale: &str,
+) {
+    let mut children = report.children.iter_mut();
+    err.children(&mut |child| {
+        let Some(report) = children.next() else {
+            return;
+        };
+        let mut path = path.clone();
+        child.path(&mut |segment| path.0.push(segment));
+        if let Some(template) = child
+            .code()
+            .and_then(|code| translator.template(locale, code))
+        {
+            report.message = render_field(template, &path, child);
+        }
+        translate_children(report, child, &path, translator, locale);
+    });
+}
+
+impl FieldErrors<'_> {
+    /// Renders the message of every error in `locale`, along with its path.
+    pub fn translate(&self, translator: &dyn Translate, locale: &str) -> Vec<(&Path, String)> {
+        self.0
+            .iter()
+            .map(|err| (&err.path, err.translate(translator, locale)))
+            .collect()
+    }
+
+    /// Returns a [translated report](FieldError::translate_report) of every error.
+    pub fn translate_report(&self, translator: &dyn Translate, locale: &str) -> Vec<Report> {
+        self.0
+            .iter()
+            .map(|err| err.translate_report(translator, locale))
+            .collect()
+    }
+}
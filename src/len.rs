@@ -1,6 +1,9 @@
 use crate::*;
-use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::ffi::{OsStr, OsString};
 use std::ops::RangeBounds;
+use std::path::{Path, PathBuf};
 
 /// Checks if the length of input is within a specified range.
 ///
@@ -31,8 +34,21 @@ where
         errors::LengthError {
             len: val.count(),
             range: self.0.clone(),
+            unit: errors::LengthUnit::Len,
         }
     }
+    #[inline]
+    fn try_verify(&self, val: &T) -> Result<(), Self::Error> {
+        let len = val.count();
+        if !self.0.contains(&len) {
+            return Err(errors::LengthError {
+                len,
+                range: self.0.clone(),
+                unit: errors::LengthUnit::Len,
+            });
+        }
+        Ok(())
+    }
 }
 
 impl<R, T> Check<&T> for Length<R>
@@ -49,12 +65,111 @@ where
             return Err(errors::LengthError {
                 len,
                 range: self.0.clone(),
+                unit: errors::LengthUnit::Len,
             });
         }
         Ok(())
     }
 }
 
+/// Checks if the number of unicode scalar values ([`char`]s) of a string is within a specified range.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// let name = "অনুপম রায়";
+/// assert!(!Length(..=10).verify(name));
+/// assert!(CharLength(..=10).verify(name));
+/// ```
+pub struct CharLength<R>(pub R);
+
+/// Checks if the number of UTF-16 code units of a string is within a specified range,
+/// matching the `maxlength` attribute of HTML inputs and `String.length` in JavaScript.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// assert!(CharLength(..=1).check("🦀").is_ok());
+/// assert!(Utf16Length(..=1).check("🦀").is_err());
+/// ```
+pub struct Utf16Length<R>(pub R);
+
+/// Checks if the number of extended grapheme clusters of a string, i.e. user-perceived
+/// characters, is within a specified range.
+///
+/// Requires the `graphemes` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// assert!(CharLength(..=1).check("🇧🇩").is_err());
+/// assert!(GraphemeLength(..=1).check("🇧🇩").is_ok());
+/// ```
+#[cfg(feature = "graphemes")]
+pub struct GraphemeLength<R>(pub R);
+
+macro_rules! unit_len {
+    [$($(#[$meta:meta])* $rule:ident => $unit:ident |$s:ident| $count:expr;)*] => [$(
+        $(#[$meta])*
+        impl<R, T> Verify<&T> for $rule<R>
+        where
+            T: ?Sized + AsRef<str>,
+            R: RangeBounds<usize> + Clone,
+        {
+            type Error = errors::LengthError<R>;
+            #[inline]
+            fn verify(&self, val: &T) -> bool {
+                let $s = val.as_ref();
+                self.0.contains(&$count)
+            }
+            #[inline]
+            fn error(&self, val: &T) -> Self::Error {
+                let $s = val.as_ref();
+                errors::LengthError {
+                    len: $count,
+                    range: self.0.clone(),
+                    unit: errors::LengthUnit::$unit,
+                }
+            }
+            #[inline]
+            fn try_verify(&self, val: &T) -> Result<(), Self::Error> {
+                Check::check(self, val)
+            }
+        }
+
+        $(#[$meta])*
+        impl<R, T> Check<&T> for $rule<R>
+        where
+            T: ?Sized + AsRef<str>,
+            R: RangeBounds<usize> + Clone,
+        {
+            type Error = errors::LengthError<R>;
+            fn check(&self, val: &T) -> Result<(), Self::Error> {
+                let $s = val.as_ref();
+                let len = $count;
+                if !self.0.contains(&len) {
+                    return Err(errors::LengthError {
+                        len,
+                        range: self.0.clone(),
+                        unit: errors::LengthUnit::$unit,
+                    });
+                }
+                Ok(())
+            }
+        }
+    )*];
+}
+
+unit_len! {
+    CharLength => Chars |s| s.chars().count();
+    Utf16Length => Utf16 |s| s.encode_utf16().count();
+    #[cfg(feature = "graphemes")]
+    GraphemeLength => Graphemes |s| unicode_segmentation::UnicodeSegmentation::graphemes(s, true).count();
+}
+
 trait GetLen {
     fn get_len(&self) -> Option<usize>;
 }
@@ -69,14 +184,38 @@ impl<T: GetLen> GetLen for Option<T> {
     }
 }
 
-impl<T: Count> GetLen for T {
+impl<T: ?Sized + Count> GetLen for T {
     #[inline]
     fn get_len(&self) -> Option<usize> {
         Some(T::count(self))
     }
 }
 
-trait Count {
+/// The length of a value, as checked by [`Length`].
+///
+/// Implemented for strings, std collections, smart pointers and, with the
+/// matching cargo features, `smallvec`, `indexmap`, `arrayvec` and `bytes`.
+/// Implement it to use [`Length`] with your own containers.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// struct Playlist {
+///     songs: Vec<String>,
+/// }
+///
+/// impl Count for Playlist {
+///     fn count(&self) -> usize {
+///         self.songs.len()
+///     }
+/// }
+///
+/// let playlist = Playlist { songs: vec!["Intro".into()] };
+/// assert!(Length(1..=100).check(&playlist).is_ok());
+/// ```
+pub trait Count {
+    /// Returns the length of the value: bytes for strings, elements for collections.
     fn count(&self) -> usize;
 }
 
@@ -87,6 +226,30 @@ impl<T, const N: usize> Count for [T; N] {
     }
 }
 
+impl<B> Count for Cow<'_, B>
+where
+    B: ?Sized + ToOwned + Count,
+{
+    #[inline]
+    fn count(&self) -> usize {
+        B::count(self)
+    }
+}
+
+impl Count for Path {
+    #[inline]
+    fn count(&self) -> usize {
+        self.as_os_str().len()
+    }
+}
+
+impl Count for PathBuf {
+    #[inline]
+    fn count(&self) -> usize {
+        self.as_os_str().len()
+    }
+}
+
 macro_rules! len {
     [$($ty:ty)*] => [$(
         impl Count for $ty {
@@ -94,7 +257,7 @@ macro_rules! len {
         }
     )*];
     [@deref $($ty: ty)*] => [$(
-        impl<T: Count> Count for $ty {
+        impl<T: ?Sized + Count> Count for $ty {
             #[inline] fn count(&self) -> usize { T::count(self) }
         }
     )*];
@@ -105,10 +268,11 @@ macro_rules! len {
     )*]
 }
 
-len! { String str }
+len! { String str OsString OsStr }
 len! {
     @deref
     &T
+    &mut T
     Box<T>
     std::rc::Rc<T>
     std::sync::Arc<T>
@@ -118,8 +282,44 @@ len! {
     <T> => [T]
     <T> => Vec<T>
     <T> => VecDeque<T>
+    <T> => LinkedList<T>
+    <T> => BinaryHeap<T>
     <K> => BTreeSet<K>
-    <K> => HashSet<K>
+    <K, S> => HashSet<K, S>
     <K, V> => BTreeMap<K, V>
-    <K, V> => HashMap<K, V>
+    <K, V, S> => HashMap<K, V, S>
 }
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> Count for smallvec::SmallVec<A> {
+    #[inline]
+    fn count(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "indexmap")]
+len! {
+    @collection
+    <K, S> => indexmap::IndexSet<K, S>
+    <K, V, S> => indexmap::IndexMap<K, V, S>
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const N: usize> Count for arrayvec::ArrayVec<T, N> {
+    #[inline]
+    fn count(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<const N: usize> Count for arrayvec::ArrayString<N> {
+    #[inline]
+    fn count(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "bytes")]
+len! { bytes::Bytes bytes::BytesMut }
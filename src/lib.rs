@@ -2,21 +2,88 @@
 #![warn(missing_docs)]
 
 mod condition;
+mod custom;
+mod each;
+mod format;
 mod len;
+mod nested;
 mod number;
+#[cfg(feature = "regex")]
+mod pattern;
+mod set;
+mod text;
 
 /// Error types used by the validation checks.
 pub mod errors;
+/// Localization of validation errors.
+pub mod i18n;
 
 pub use condition::*;
+pub use custom::*;
+pub use each::*;
+pub use format::*;
 pub use len::*;
 pub use number::*;
+#[cfg(feature = "regex")]
+pub use pattern::*;
+pub use set::*;
+pub use text::*;
+/// `nested` is only supported on fields, as a container rule it would
+/// validate the value itself again:
+///
+/// ```compile_fail
+/// # use validex::*;
+/// #[derive(Check)]
+/// #[check(nested)]
+/// struct Loop {
+///     #[check(Length(1..))]
+///     name: String,
+/// }
+/// ```
 pub use validex_macros::Check;
+/// An invalid expression fails the build:
+///
+/// ```compile_fail
+/// # use validex::*;
+/// let slug = Pattern!("^[a-z+$");
+/// ```
+#[cfg(feature = "regex")]
+pub use validex_macros::Pattern;
 
 /// A dynamic error type.
 pub type DynError<'err> = Box<dyn std::error::Error + Send + Sync + 'err>;
 
 /// A trait that powers conditional combinators [`Not`], [`All`], [`Any`]
+///
+/// [`Verify::try_verify`] verifies and produces the error in a single pass. Its
+/// default implementation calls [`Verify::verify`], then [`Verify::error`] on failure,
+/// so rules that are expensive to evaluate should override it.
+///
+/// ### Example
+///
+/// ```rust
+/// # use validex::*;
+/// struct Even;
+///
+/// impl Verify<&u32> for Even {
+///     type Error = String;
+///     fn verify(&self, val: &u32) -> bool {
+///         self.try_verify(val).is_ok()
+///     }
+///     fn error(&self, val: &u32) -> Self::Error {
+///         self.try_verify(val).unwrap_err()
+///     }
+///     fn try_verify(&self, val: &u32) -> Result<(), Self::Error> {
+///         match val % 2 {
+///             0 => Ok(()),
+///             _ => Err(format!("{val} is odd")),
+///         }
+///     }
+/// }
+///
+/// assert!(Not(Even).verify(&3));
+/// assert_eq!(Even.try_verify(&3), Err("3 is odd".into()));
+/// ```
 pub trait Verify<Args> {
     /// The error type produced when verify fails.
     type Error;
@@ -25,7 +92,116 @@ pub trait Verify<Args> {
     fn verify(&self, _: Args) -> bool;
 
     /// Produces an error for the given arguments.
+    ///
+    /// It is also called for arguments that pass, e.g. by [`Not`], so it must
+    /// not panic.
     fn error(&self, _: Args) -> Self::Error;
+
+    /// Verifies the given arguments, producing the error if they fail.
+    ///
+    /// The `Args: Copy` bound lets the default implementation pass the arguments
+    /// to both [`Verify::verify`] and [`Verify::error`]. Rules are checked against
+    /// references, which are always `Copy`, so it only shows up in implementations
+    /// generic over `Args`: those forwarding to an inner rule's `try_verify` must
+    /// repeat it, while those that don't, such as [`Checked`], can leave it out.
+    #[inline]
+    fn try_verify(&self, args: Args) -> Result<(), Self::Error>
+    where
+        Args: Copy,
+    {
+        if !self.verify(args) {
+            return Err(self.error(args));
+        }
+        Ok(())
+    }
+}
+
+/// A type whose fields can be validated as a whole.
+///
+/// This trait is implemented by `#[derive(Check)]`, so derived types can be
+/// used as generic bounds and nested inside other rules via [`Valid`].
+///
+/// ### Example
+///
+/// ```rust
+/// # use validex::*;
+/// #[derive(Check)]
+/// struct Input {
+///     #[check(Range(1..=10))]
+///     id: u32,
+/// }
+///
+/// fn save<T: Validate>(input: &T) -> bool {
+///     input.check().is_ok()
+/// }
+///
+/// assert!(save(&Input { id: 7 }));
+/// assert!(!save(&Input { id: 42 }));
+/// ```
+pub trait Validate {
+    /// The error returned by [`Validate::check`].
+    type Error<'e>: Into<errors::FieldError<'e>>
+    where
+        Self: 'e;
+
+    /// The error returned by [`Validate::check_all`].
+    type Errors<'e>: IntoIterator<Item = Self::Error<'e>>
+    where
+        Self: 'e;
+
+    /// Validates every rule, stopping at the first failure.
+    fn check(&self) -> Result<(), Self::Error<'_>>;
+
+    /// Validates every rule, collecting all failures.
+    fn check_all(&self) -> Result<(), Self::Errors<'_>>;
+}
+
+/// A type whose rules can be validated into a typed error enum.
+///
+/// This trait is implemented by `#[derive(Check)]` when the container attribute
+/// starts with `error = Name`. The generated `Name` enum has one variant per
+/// checked field, and one for the container rules, named after the type.
+/// A trait method, unlike an inherent one, does not collide with methods of the type itself.
+///
+/// The types of the rule expressions cannot be named in a signature, so each variant
+/// holds the error of the rule that failed as an opaque type. Match on the variant to
+/// find what failed, and read the error through [`Params`](errors::Params) and `Display`.
+///
+/// ### Example
+///
+/// ```rust
+/// # use validex::*;
+/// #[derive(Check)]
+/// #[check(error = InputError)]
+/// struct Input {
+///     #[check(Range(1..=10))]
+///     id: u32,
+/// }
+///
+/// let err = Input { id: 42 }.check_typed().unwrap_err();
+/// assert!(matches!(err, InputError::Id(_)));
+/// assert_eq!(err.to_string(), "id -> expected 42 in 1..=10");
+///
+/// use validex::errors::Params;
+/// assert_eq!(err.code(), Some("range"));
+/// ```
+///
+/// Two variants with the same name, here for the `input` field and
+/// the container rules, fail the build:
+///
+/// ```compile_fail
+/// # use validex::*;
+/// # fn is_known(_: &Input) -> Result<(), &'static str> { Ok(()) }
+/// #[derive(Check)]
+/// #[check(error = InputError, is_known)]
+/// struct Input {
+///     #[check(Length(1..))]
+///     input: String,
+/// }
+/// ```
+pub trait ValidateTyped {
+    /// Validates every rule, stopping at the first failure.
+    fn check_typed(&self) -> Result<(), impl errors::ValidationError>;
 }
 
 /// Trait for performing a check on a field.
@@ -37,6 +213,11 @@ pub trait Verify<Args> {
 ///
 /// Every function `Fn(&T) -> Result<(), E>` automatically implements this trait.
 ///
+/// To be used in `#[check(...)]`, the error type must convert into a [`DynError`],
+/// as `String`, `&str` and any [`std::error::Error`] do. Error types that also
+/// implement [`errors::Params`] expose their code and parameters to message
+/// templates and [reports](errors::Report).
+///
 /// ### Example
 ///
 /// ```rust
@@ -88,17 +269,101 @@ where
     T: ?Sized,
     V: Verify<&'a T>,
 {
-    if !this.verify(val) {
-        return Err(this.error(val));
+    this.try_verify(val)
+}
+
+#[doc(hidden)]
+pub fn __field<'e>(
+    path: &[errors::Segment],
+    result: Result<(), Box<dyn errors::ValidationError + 'e>>,
+) -> Result<(), errors::FieldError<'e>> {
+    __custom(path, None, None, result)
+}
+
+#[doc(hidden)]
+pub fn __custom<'e>(
+    path: &[errors::Segment],
+    code: Option<&'static str>,
+    message: Option<&'static str>,
+    result: Result<(), Box<dyn errors::ValidationError + 'e>>,
+) -> Result<(), errors::FieldError<'e>> {
+    result.map_err(|error| {
+        let kind = error.code();
+        let mut path = path.to_vec();
+        error.path(&mut |segment| path.push(segment));
+        errors::FieldError {
+            path: errors::Path(path),
+            code: code.or(kind),
+            message,
+            kind,
+            error,
+        }
+    })
+}
+
+// Boxes the error of a rule in `#[check(...)]`, keeping its `Params` when implemented.
+// Called as `(&err).__kind().boxed(err)`, so that method resolution prefers
+// `__ParamsKind` over `__OpaqueKind`, which is only reached through autoref.
+#[doc(hidden)]
+pub struct __Params;
+#[doc(hidden)]
+pub struct __Opaque;
+
+#[doc(hidden)]
+pub trait __ParamsKind {
+    #[inline]
+    fn __kind(&self) -> __Params {
+        __Params
+    }
+}
+impl<E: errors::ValidationError> __ParamsKind for E {}
+
+#[doc(hidden)]
+pub trait __OpaqueKind {
+    #[inline]
+    fn __kind(&self) -> __Opaque {
+        __Opaque
+    }
+}
+impl<E> __OpaqueKind for &E {}
+
+impl __Params {
+    #[inline]
+    pub fn boxed<'e, E>(self, error: E) -> Box<dyn errors::ValidationError + 'e>
+    where
+        E: errors::ValidationError + 'e,
+    {
+        Box::new(error)
+    }
+}
+
+impl __Opaque {
+    #[inline]
+    pub fn boxed<'e, E>(self, error: E) -> Box<dyn errors::ValidationError + 'e>
+    where
+        E: Into<DynError<'e>>,
+    {
+        Box::new(errors::Opaque(error.into()))
     }
-    Ok(())
 }
 
 #[doc(hidden)]
-pub fn __field<'e, V, T>(key: &'static str, this: &V, val: T) -> Result<(), errors::FieldError<'e>>
+pub fn __nested<'e, T>(path: &[errors::Segment], val: &'e T) -> Result<(), errors::FieldError<'e>>
 where
-    V: Check<T>,
-    V::Error: Into<DynError<'e>>,
+    T: ?Sized + Validate,
 {
-    Check::check(this, val).map_err(|err| errors::FieldError::new(key, err))
+    val.check().map_err(|err| err.into().prefix(path))
+}
+
+#[doc(hidden)]
+pub fn __nested_all<'e, T>(
+    path: &[errors::Segment],
+    val: &'e T,
+    errors: &mut Vec<errors::FieldError<'e>>,
+) where
+    T: ?Sized + Validate,
+{
+    if let Err(errs) = val.check_all() {
+        errors.extend(errs.into_iter().map(|err| err.into().prefix(path)));
+    }
 }
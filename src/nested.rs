@@ -0,0 +1,120 @@
+use crate::errors::{FieldError, FieldErrors, Segment};
+use crate::*;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt::Display;
+
+fn each<'e, I, T>(iter: I) -> Result<(), FieldError<'e>>
+where
+    I: Iterator<Item = (Segment, &'e T)>,
+    T: Validate + 'e,
+{
+    for (segment, val) in iter {
+        val.check().map_err(|err| err.into().prefix(&[segment]))?;
+    }
+    Ok(())
+}
+
+fn each_all<'e, I, T>(iter: I) -> Result<(), FieldErrors<'e>>
+where
+    I: Iterator<Item = (Segment, &'e T)>,
+    T: Validate + 'e,
+{
+    let mut errors = Vec::new();
+    for (segment, val) in iter {
+        if let Err(errs) = val.check_all() {
+            let path = [segment];
+            errors.extend(errs.into_iter().map(|err| err.into().prefix(&path)));
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(FieldErrors(errors))
+    }
+}
+
+macro_rules! nested {
+    [@deref $($ty:ty)*] => [$(
+        impl<T> Validate for $ty where T: ?Sized + Validate {
+            type Error<'e> = T::Error<'e> where Self: 'e;
+            type Errors<'e> = T::Errors<'e> where Self: 'e;
+            #[inline]
+            fn check(&self) -> Result<(), Self::Error<'_>> {
+                T::check(self)
+            }
+            #[inline]
+            fn check_all(&self) -> Result<(), Self::Errors<'_>> {
+                T::check_all(self)
+            }
+        }
+    )*];
+    [@collection $([$($p:tt)*] => $ty:ty)*] => [$(
+        impl<$($p)*> Validate for $ty where T: Validate {
+            type Error<'e> = FieldError<'e> where Self: 'e;
+            type Errors<'e> = FieldErrors<'e> where Self: 'e;
+            fn check(&self) -> Result<(), Self::Error<'_>> {
+                each(self.iter().enumerate().map(|(idx, val)| (Segment::Index(idx), val)))
+            }
+            fn check_all(&self) -> Result<(), Self::Errors<'_>> {
+                each_all(self.iter().enumerate().map(|(idx, val)| (Segment::Index(idx), val)))
+            }
+        }
+    )*];
+    [@map $([$($p:tt)*] => $ty:ty)*] => [$(
+        impl<$($p)*> Validate for $ty where K: Display, T: Validate {
+            type Error<'e> = FieldError<'e> where Self: 'e;
+            type Errors<'e> = FieldErrors<'e> where Self: 'e;
+            fn check(&self) -> Result<(), Self::Error<'_>> {
+                each(self.iter().map(|(key, val)| (Segment::Key(key.to_string()), val)))
+            }
+            fn check_all(&self) -> Result<(), Self::Errors<'_>> {
+                each_all(self.iter().map(|(key, val)| (Segment::Key(key.to_string()), val)))
+            }
+        }
+    )*];
+}
+
+nested! {
+    @deref
+    &T
+    Box<T>
+    std::rc::Rc<T>
+    std::sync::Arc<T>
+}
+nested! {
+    @collection
+    [T] => [T]
+    [T, const N: usize] => [T; N]
+    [T] => Vec<T>
+    [T] => VecDeque<T>
+}
+nested! {
+    @map
+    [K, T] => BTreeMap<K, T>
+    [K, T, S] => HashMap<K, T, S>
+}
+
+impl<T: Validate> Validate for Option<T> {
+    type Error<'e>
+        = T::Error<'e>
+    where
+        Self: 'e;
+    type Errors<'e>
+        = T::Errors<'e>
+    where
+        Self: 'e;
+    #[inline]
+    fn check(&self) -> Result<(), Self::Error<'_>> {
+        match self {
+            None => Ok(()),
+            Some(val) => val.check(),
+        }
+    }
+    #[inline]
+    fn check_all(&self) -> Result<(), Self::Errors<'_>> {
+        match self {
+            None => Ok(()),
+            Some(val) => val.check_all(),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::*;
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+/// Checks if a string matches a regular expression.
+///
+/// The expression is compiled once, on first use, and cached. Prefer the
+/// [`Pattern!`](macro@crate::Pattern) macro, which checks the expression at
+/// compile time and keeps the compiled expression in a `static`, so it is
+/// not recompiled on every `check()` of a derived type, where the rule
+/// expression is evaluated on each call.
+///
+/// Requires the `regex` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// #[derive(Check)]
+/// struct Input {
+///   #[check(Pattern!("^[a-z0-9-]+$"), Not(Pattern!("--")))]
+///   slug: String,
+/// }
+///
+/// let input = Input { slug: "hello--world".into() };
+/// assert!(input.check().is_err());
+///
+/// let digits = Pattern::new(r"^\d+$");
+/// assert!(digits.verify("42"));
+/// ```
+pub struct Pattern {
+    source: Cow<'static, str>,
+    regex: Cache,
+}
+
+enum Cache {
+    Owned(OnceLock<regex::Regex>),
+    Static(&'static OnceLock<regex::Regex>),
+}
+
+#[doc(hidden)]
+pub type __PatternCache = OnceLock<regex::Regex>;
+
+impl Pattern {
+    /// Creates a pattern from a regular expression, compiled on first use.
+    ///
+    /// # Panics
+    ///
+    /// Checking a value panics if the expression is invalid.
+    pub const fn new(source: &'static str) -> Pattern {
+        Pattern {
+            source: Cow::Borrowed(source),
+            regex: Cache::Owned(OnceLock::new()),
+        }
+    }
+
+    #[doc(hidden)]
+    pub const fn __cached(source: &'static str, cache: &'static __PatternCache) -> Pattern {
+        Pattern {
+            source: Cow::Borrowed(source),
+            regex: Cache::Static(cache),
+        }
+    }
+
+    /// Returns the source of the regular expression.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the compiled regular expression.
+    pub fn regex(&self) -> &regex::Regex {
+        let cache = match &self.regex {
+            Cache::Owned(cache) => cache,
+            Cache::Static(cache) => cache,
+        };
+        cache.get_or_init(|| match regex::Regex::new(&self.source) {
+            Ok(regex) => regex,
+            Err(err) => panic!("invalid pattern: {err}"),
+        })
+    }
+}
+
+impl From<regex::Regex> for Pattern {
+    fn from(regex: regex::Regex) -> Pattern {
+        Pattern {
+            source: Cow::Owned(regex.as_str().into()),
+            regex: Cache::Owned(OnceLock::from(regex)),
+        }
+    }
+}
+
+impl<'a, T> Verify<&'a T> for Pattern
+where
+    T: ?Sized + AsRef<str>,
+{
+    type Error = errors::PatternError<'a>;
+    #[inline]
+    fn verify(&self, val: &'a T) -> bool {
+        self.regex().is_match(val.as_ref())
+    }
+    #[inline]
+    fn error(&self, val: &'a T) -> Self::Error {
+        errors::PatternError {
+            value: val.as_ref(),
+            pattern: self.source.clone(),
+        }
+    }
+}
+
+impl<'a, T> Check<&'a T> for Pattern
+where
+    T: ?Sized + AsRef<str>,
+{
+    type Error = errors::PatternError<'a>;
+    fn check(&self, val: &'a T) -> Result<(), Self::Error> {
+        check(self, val)
+    }
+}
@@ -0,0 +1,185 @@
+use crate::*;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hash};
+
+/// Checks if a value is one of the values of a [`Set`].
+///
+/// Unlike `Any((a, b, ...))`, it is not limited in size, uses hashing or binary
+/// search when the set supports it, and fails with a single error listing the
+/// expected values.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// # use std::collections::HashSet;
+/// #[derive(Check)]
+/// struct Order {
+///   #[check(In(["usd", "eur", "gbp"]))]
+///   currency: String,
+///   #[check(In(Sorted([1, 2, 3, 5, 8, 13, 21])))]
+///   points: u32,
+/// }
+///
+/// let order = Order { currency: "jpy".into(), points: 5 };
+/// let err = order.check().unwrap_err();
+/// assert_eq!(err.to_string(), r#"currency -> expected one of ["usd", "eur", "gbp"]"#);
+///
+/// let reserved = HashSet::from(["admin", "root"]);
+/// assert!(NotIn(&reserved).verify(&"ferris"));
+/// ```
+pub struct In<S>(pub S);
+
+/// Checks if a value is none of the values of a [`Set`].
+///
+/// See [`In`] for an example.
+pub struct NotIn<S>(pub S);
+
+/// A sorted slice, searched with a binary search by [`In`] and [`NotIn`].
+///
+/// The values must be sorted in ascending order, otherwise the result is unspecified,
+/// and comparable with the checked value: use `Sorted(["a", "b"])` for `&str` values,
+/// and `Sorted([String::from("a"), ...])` or a `BTreeSet` for `String` values.
+pub struct Sorted<S>(pub S);
+
+/// A collection of values, as used by [`In`] and [`NotIn`].
+///
+/// Implemented for slices, arrays and `Vec` (linear search), [`Sorted`] slices
+/// (binary search), `HashSet` and `BTreeSet`.
+pub trait Set<T: ?Sized> {
+    /// Returns `true` if the set contains the value.
+    fn contains(&self, value: &T) -> bool;
+
+    /// Calls `f` with each value of the set, used to list the expected values in errors.
+    fn values(&self, _f: &mut dyn FnMut(&dyn Debug)) {}
+}
+
+impl<S, T> Set<T> for &S
+where
+    S: ?Sized + Set<T>,
+    T: ?Sized,
+{
+    #[inline]
+    fn contains(&self, value: &T) -> bool {
+        S::contains(self, value)
+    }
+    fn values(&self, f: &mut dyn FnMut(&dyn Debug)) {
+        S::values(self, f)
+    }
+}
+
+macro_rules! slice {
+    [$([$($p:tt)*] => $ty:ty)*] => [$(
+        impl<T, U, $($p)*> Set<T> for $ty
+        where
+            T: ?Sized,
+            U: PartialEq<T> + Debug,
+        {
+            #[inline]
+            fn contains(&self, value: &T) -> bool {
+                self.iter().any(|item| item == value)
+            }
+            fn values(&self, f: &mut dyn FnMut(&dyn Debug)) {
+                self.iter().for_each(|item| f(item));
+            }
+        }
+    )*];
+}
+
+slice! {
+    [] => [U]
+    [const N: usize] => [U; N]
+    [] => Vec<U>
+}
+
+macro_rules! sorted {
+    [$([$($p:tt)*] => $ty:ty)*] => [$(
+        impl<T, U, $($p)*> Set<T> for Sorted<$ty>
+        where
+            T: ?Sized,
+            U: PartialOrd<T> + Debug,
+        {
+            #[inline]
+            fn contains(&self, value: &T) -> bool {
+                self.0
+                    .binary_search_by(|item| item.partial_cmp(value).unwrap_or(Ordering::Less))
+                    .is_ok()
+            }
+            fn values(&self, f: &mut dyn FnMut(&dyn Debug)) {
+                self.0.iter().for_each(|item| f(item));
+            }
+        }
+    )*];
+}
+
+sorted! {
+    [] => &[U]
+    [const N: usize] => [U; N]
+    [] => Vec<U>
+}
+
+impl<T, K, H> Set<T> for HashSet<K, H>
+where
+    T: ?Sized + Hash + Eq,
+    K: std::borrow::Borrow<T> + Hash + Eq + Debug,
+    H: BuildHasher,
+{
+    #[inline]
+    fn contains(&self, value: &T) -> bool {
+        HashSet::contains(self, value)
+    }
+    fn values(&self, f: &mut dyn FnMut(&dyn Debug)) {
+        self.iter().for_each(|item| f(item));
+    }
+}
+
+impl<T, K> Set<T> for BTreeSet<K>
+where
+    T: ?Sized + Ord,
+    K: std::borrow::Borrow<T> + Ord + Debug,
+{
+    #[inline]
+    fn contains(&self, value: &T) -> bool {
+        BTreeSet::contains(self, value)
+    }
+    fn values(&self, f: &mut dyn FnMut(&dyn Debug)) {
+        self.iter().for_each(|item| f(item));
+    }
+}
+
+macro_rules! membership {
+    [$($rule:ident => $expected:literal;)*] => [$(
+        impl<'a, S, T> Verify<&'a T> for $rule<S>
+        where
+            S: Set<T>,
+            T: ?Sized + Debug,
+        {
+            type Error = errors::MembershipError<&'a T>;
+            #[inline]
+            fn verify(&self, val: &'a T) -> bool {
+                self.0.contains(val) == $expected
+            }
+            fn error(&self, value: &'a T) -> Self::Error {
+                errors::MembershipError::new(value, $expected, &self.0)
+            }
+        }
+
+        impl<'a, S, T> Check<&'a T> for $rule<S>
+        where
+            S: Set<T>,
+            T: ?Sized + Debug,
+        {
+            type Error = errors::MembershipError<&'a T>;
+            fn check(&self, val: &'a T) -> Result<(), Self::Error> {
+                check(self, val)
+            }
+        }
+    )*];
+}
+
+membership! {
+    In => true;
+    NotIn => false;
+}
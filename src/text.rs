@@ -0,0 +1,216 @@
+use crate::*;
+
+/// Checks if a string contains only ASCII characters.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// assert!(Ascii.verify("hello"));
+/// let err = Ascii.check("héllo").unwrap_err();
+/// assert_eq!((err.char, err.offset, err.index), ('é', 1, 1));
+/// ```
+pub struct Ascii;
+
+/// Checks if a string contains only alphabetic or numeric characters, as defined by
+/// [`char::is_alphanumeric`], which includes non-ASCII letters and digits.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// assert!(Alphanumeric.verify("abc123"));
+/// assert!(!Alphanumeric.verify("abc-123"));
+/// ```
+pub struct Alphanumeric;
+
+/// Checks if every character of a string satisfies a predicate.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// let hex = Chars(|c: char| c.is_ascii_hexdigit());
+/// assert!(hex.verify("c0ffee"));
+/// assert_eq!(hex.check("coffee").unwrap_err().offset, 1);
+/// ```
+pub struct Chars<F>(pub F);
+
+/// Checks if a string contains no uppercase characters.
+pub struct Lowercase;
+
+/// Checks if a string contains no lowercase characters.
+pub struct Uppercase;
+
+/// Checks if a string contains no control characters, as defined by
+/// [`char::is_control`], including line breaks and tabs.
+pub struct NoControlChars;
+
+/// Checks if a string has no leading or trailing whitespace.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// assert!(Trimmed.verify("hello world"));
+/// assert_eq!(Trimmed.check("hello ").unwrap_err().offset, 5);
+/// ```
+pub struct Trimmed;
+
+/// Checks if a string contains at least one non-whitespace character.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// assert!(NonBlank.verify(" a "));
+/// assert!(!NonBlank.verify(" \t\n"));
+/// ```
+pub struct NonBlank;
+
+/// Checks if a string contains a substring.
+///
+/// # Example
+///
+/// ```rust
+/// # use validex::*;
+/// #[derive(Check)]
+/// struct Upload {
+///   #[check(StartsWith("/uploads/"), Not(Contains("..")), EndsWith(".png"))]
+///   path: String,
+/// }
+/// ```
+pub struct Contains<S>(pub S);
+
+/// Checks if a string starts with a prefix.
+pub struct StartsWith<S>(pub S);
+
+/// Checks if a string ends with a suffix.
+pub struct EndsWith<S>(pub S);
+
+macro_rules! chars {
+    [$($code:literal: [$($p:tt)*] $ty:ty => |$this:ident, $s:ident| $find:expr;)*] => [$(
+        impl<'a, T, $($p)*> Verify<&'a T> for $ty
+        where
+            T: ?Sized + AsRef<str>,
+        {
+            type Error = errors::CheckedError<errors::CharError<'a>>;
+            #[inline]
+            fn verify(&self, val: &'a T) -> bool {
+                let ($this, $s) = (self, val.as_ref());
+                $find.is_none()
+            }
+            fn error(&self, val: &'a T) -> Self::Error {
+                match Check::check(self, val) {
+                    Ok(()) => errors::CheckedError::Passed($code),
+                    Err(err) => errors::CheckedError::Failed(err),
+                }
+            }
+            #[inline]
+            fn try_verify(&self, val: &'a T) -> Result<(), Self::Error> {
+                Check::check(self, val).map_err(errors::CheckedError::Failed)
+            }
+        }
+
+        impl<'a, T, $($p)*> Check<&'a T> for $ty
+        where
+            T: ?Sized + AsRef<str>,
+        {
+            type Error = errors::CharError<'a>;
+            fn check(&self, val: &'a T) -> Result<(), Self::Error> {
+                let ($this, $s) = (self, val.as_ref());
+                match $find {
+                    None => Ok(()),
+                    Some((offset, char)) => Err(errors::CharError::new($code, $s, offset, char)),
+                }
+            }
+        }
+    )*];
+}
+
+chars! {
+    "ascii": [] Ascii => |_this, s| find(s, |c| !c.is_ascii());
+    "alphanumeric": [] Alphanumeric => |_this, s| find(s, |c| !c.is_alphanumeric());
+    "chars": [F: Fn(char) -> bool] Chars<F> => |this, s| find(s, |c| !(this.0)(c));
+    "lowercase": [] Lowercase => |_this, s| find(s, char::is_uppercase);
+    "uppercase": [] Uppercase => |_this, s| find(s, char::is_lowercase);
+    "control": [] NoControlChars => |_this, s| find(s, char::is_control);
+    "trimmed": [] Trimmed => |_this, s| {
+        let first = s.char_indices().next().filter(|(_, c)| c.is_whitespace());
+        // Trailing whitespace is reported from the start of the run.
+        let end = s.trim_end().len();
+        first.or_else(|| s[end..].chars().next().map(|c| (end, c)))
+    };
+}
+
+fn find(s: &str, f: impl Fn(char) -> bool) -> Option<(usize, char)> {
+    s.char_indices().find(|&(_, c)| f(c))
+}
+
+macro_rules! content {
+    [$($code:literal: $rule:ident => $method:ident;)*] => [$(
+        impl<'a, T, S> Verify<&'a T> for $rule<S>
+        where
+            T: ?Sized + AsRef<str>,
+            S: AsRef<str> + Clone,
+        {
+            type Error = errors::ContentError<'a, S>;
+            #[inline]
+            fn verify(&self, val: &'a T) -> bool {
+                val.as_ref().$method(self.0.as_ref())
+            }
+            #[inline]
+            fn error(&self, val: &'a T) -> Self::Error {
+                errors::ContentError {
+                    code: $code,
+                    value: val.as_ref(),
+                    pattern: self.0.clone(),
+                }
+            }
+        }
+
+        impl<'a, T, S> Check<&'a T> for $rule<S>
+        where
+            T: ?Sized + AsRef<str>,
+            S: AsRef<str> + Clone,
+        {
+            type Error = errors::ContentError<'a, S>;
+            fn check(&self, val: &'a T) -> Result<(), Self::Error> {
+                check(self, val)
+            }
+        }
+    )*];
+}
+
+content! {
+    "contains": Contains => contains;
+    "starts_with": StartsWith => starts_with;
+    "ends_with": EndsWith => ends_with;
+}
+
+impl<'a, T> Verify<&'a T> for NonBlank
+where
+    T: ?Sized + AsRef<str>,
+{
+    type Error = errors::BlankError<'a>;
+    #[inline]
+    fn verify(&self, val: &'a T) -> bool {
+        !val.as_ref().trim().is_empty()
+    }
+    #[inline]
+    fn error(&self, val: &'a T) -> Self::Error {
+        errors::BlankError {
+            value: val.as_ref(),
+        }
+    }
+}
+
+impl<'a, T> Check<&'a T> for NonBlank
+where
+    T: ?Sized + AsRef<str>,
+{
+    type Error = errors::BlankError<'a>;
+    fn check(&self, val: &'a T) -> Result<(), Self::Error> {
+        check(self, val)
+    }
+}
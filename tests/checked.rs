@@ -0,0 +1,74 @@
+mod common;
+
+use common::{Address, address};
+use validex::errors::Params;
+use validex::*;
+
+fn validate_user_id(id: &u32) -> Result<(), &'static str> {
+    if *id == 13 {
+        return Err("13 is an unlucky number");
+    }
+    Ok(())
+}
+
+fn is_admin(id: &u32) -> Result<(), &'static str> {
+    if *id != 1 {
+        return Err("not an admin");
+    }
+    Ok(())
+}
+
+fn is_remote(address: &Address) -> Result<(), &'static str> {
+    if address.city != "remote" {
+        return Err("not remote");
+    }
+    Ok(())
+}
+
+#[derive(Check)]
+struct User {
+    #[check(Any((Checked(is_admin), All((Checked(validate_user_id), Range(10..))))))]
+    id: u32,
+}
+
+#[derive(Check)]
+struct Shipping {
+    #[check(Any((Checked(Valid), Checked(is_remote))))]
+    address: Address,
+}
+
+#[test]
+fn any_and_all() {
+    assert!(User { id: 42 }.check().is_ok());
+    assert!(User { id: 1 }.check().is_ok());
+
+    let unlucky = User { id: 13 };
+    let err = unlucky.check().unwrap_err();
+    assert_eq!(err.code, Some("any"));
+    let report = err.report();
+    assert_eq!(report.children[0].message, "not an admin");
+    assert_eq!(report.children[1].message, "13 is an unlucky number");
+}
+
+#[test]
+fn nested() {
+    let shipping = Shipping {
+        address: address("Dhaka"),
+    };
+    assert!(shipping.check().is_ok());
+
+    let empty = Shipping {
+        address: address(""),
+    };
+    let err = empty.check().unwrap_err();
+    assert_eq!(err.path.to_string(), "address");
+    assert_eq!(err.report().children[0].message, "expected length 0 in 1..");
+}
+
+#[test]
+fn not() {
+    assert!(Not(Checked(validate_user_id)).check(&13).is_ok());
+    let err = Not(Checked(validate_user_id)).check(&7).unwrap_err();
+    assert_eq!(err.code(), Some("not"));
+    assert_eq!(err.to_string(), "Not: expected checked to fail");
+}
@@ -0,0 +1,20 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use validex::*;
+
+#[derive(Check)]
+pub struct Address {
+    #[check(Length(1..))]
+    pub city: String,
+}
+
+pub fn address(city: &str) -> Address {
+    Address { city: city.into() }
+}
+
+/// Returns the path of the first error of `val`, which must fail.
+pub fn failing_path(val: &impl Validate) -> String {
+    let err: errors::FieldError = val.check().unwrap_err().into();
+    err.path.to_string()
+}
@@ -0,0 +1,81 @@
+use validex::errors::Segment;
+use validex::*;
+
+#[derive(Check)]
+#[check(password_not_username, passwords_match, field = password_confirm)]
+struct Signup {
+    username: String,
+    #[check(Length(8..))]
+    password: String,
+    password_confirm: String,
+}
+
+fn password_not_username(signup: &Signup) -> Result<(), &'static str> {
+    if signup.password == signup.username {
+        return Err("password must differ from the username");
+    }
+    Ok(())
+}
+
+fn passwords_match(signup: &Signup) -> Result<(), &'static str> {
+    if signup.password != signup.password_confirm {
+        return Err("passwords do not match");
+    }
+    Ok(())
+}
+
+fn signup(username: &str, password: &str, password_confirm: &str) -> Signup {
+    Signup {
+        username: username.into(),
+        password: password.into(),
+        password_confirm: password_confirm.into(),
+    }
+}
+
+#[derive(Check)]
+#[check(email_or_phone)]
+enum Contact {
+    Email(#[check(Length(3..))] String),
+    Phone(String),
+}
+
+fn email_or_phone(contact: &Contact) -> Result<(), &'static str> {
+    match contact {
+        Contact::Phone(phone) if phone.is_empty() => Err("phone must be set"),
+        _ => Ok(()),
+    }
+}
+
+#[test]
+fn struct_rules() {
+    let horse = "correct horse";
+    assert!(signup("ferris", horse, horse).check().is_ok());
+
+    let same = signup(horse, horse, horse);
+    let err = same.check().unwrap_err();
+    assert!(err.path.0.is_empty());
+    assert_eq!(err.to_string(), "password must differ from the username");
+
+    let mismatch = signup("ferris", horse, "battery staple");
+    let err = mismatch.check().unwrap_err();
+    assert_eq!(err.path.0, [Segment::Field("password_confirm")]);
+}
+
+#[test]
+fn runs_after_field_rules() {
+    let signup = signup("short", "short", "");
+    let errors = signup.check_all().unwrap_err();
+    let paths: Vec<_> = errors.0.iter().map(|err| err.path.to_string()).collect();
+    assert_eq!(paths, ["password", "", "password_confirm"]);
+}
+
+#[test]
+fn enum_rules() {
+    assert!(Contact::Phone("+8801".into()).check().is_ok());
+    assert!(Contact::Email("me@example.com".into()).check().is_ok());
+    let contact = Contact::Phone("".into());
+    assert_eq!(
+        contact.check().unwrap_err().to_string(),
+        "phone must be set"
+    );
+}
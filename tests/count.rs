@@ -0,0 +1,81 @@
+use validex::*;
+
+fn has_digit(s: &impl AsRef<str>) -> bool {
+    s.as_ref().chars().any(|c| c.is_ascii_digit())
+}
+
+#[derive(Check)]
+struct Account {
+    #[check(AtLeast(2, (Length(12..), Pred(has_digit, "no digit"), Not(Lowercase))))]
+    password: String,
+}
+
+#[test]
+fn passes() {
+    let account = Account {
+        password: "Hunter2".into(),
+    };
+    assert!(account.check().is_ok());
+    assert!(ExactlyOne((Contains("@"), StartsWith("+"))).verify("me@example.com"));
+    assert!(AtMost(1, (Range(0..=10), Range(5..=15), 7)).verify(&3));
+    assert!(Xor((Range(1..=9), Range(5..=20))).verify(&3));
+}
+
+#[test]
+fn at_least_reports_counts_and_failures() {
+    let account = Account {
+        password: "hunter".into(),
+    };
+    let err = account.check().unwrap_err();
+    assert_eq!(err.code, Some("at_least"));
+    let report = err.report();
+    assert_eq!(report.params["passed"], errors::ParamValue::Int(0));
+    assert_eq!(report.params["min"], errors::ParamValue::Int(2));
+    assert_eq!(report.params["max"], errors::ParamValue::Int(3));
+    assert_eq!(report.children.len(), 3);
+    assert_eq!(report.children[1].message, "no digit");
+}
+
+#[test]
+fn exactly_one() {
+    let contact = ExactlyOne((Contains("@"), StartsWith("+")));
+    for value in ["+1@example.com", "nobody"] {
+        let err = contact.check(value).unwrap_err();
+        assert_eq!(err.kind, errors::CountKind::ExactlyOne);
+    }
+}
+
+#[test]
+fn at_most_and_xor() {
+    let err = AtMost(1, (Range(0..=10), Range(5..=15), 7))
+        .check(&7)
+        .unwrap_err();
+    assert_eq!(err.passed, 3);
+    assert_eq!(err.to_string(), "expected at most 1 rule to pass, 3 passed");
+
+    assert!(Xor((Range(0..=10), Range(5..=15))).verify(&12));
+    assert!(!Xor((Range(0..=10), Range(5..=15))).verify(&20));
+    assert_eq!(
+        Xor((Range(0..=10), Range(5..=15)))
+            .check(&7)
+            .unwrap_err()
+            .kind,
+        errors::CountKind::Xor
+    );
+}
+
+#[test]
+fn too_many_passes_report_no_failures() {
+    let err = AtMost(1, (Range(0..=10), Range(5..=15), Range(20..)))
+        .check(&7)
+        .unwrap_err();
+    assert_eq!(err.passed, 2);
+    assert!(err.failures.is_empty());
+    assert_eq!(err.to_string(), "expected at most 1 rule to pass, 2 passed");
+}
+
+#[test]
+fn does_not_shadow_count() {
+    let values = vec![1u32, 2];
+    assert_eq!(values.count(), 2);
+}
@@ -0,0 +1,189 @@
+use validex::*;
+
+#[derive(Check)]
+struct Signup {
+    #[check(
+        Length(3..=32), code = "username_length", message = "Username must be between 3 and 32 characters",
+        Not("admin"), code = "username_reserved",
+    )]
+    username: String,
+    #[check(Range(13..), message = "You must be at least 13 years old")]
+    age: u32,
+}
+
+#[test]
+fn custom_code_and_message() {
+    let signup = Signup {
+        username: "al".into(),
+        age: 12,
+    };
+    let errors = signup.check_all().unwrap_err();
+
+    assert_eq!(errors.0[0].code, Some("username_length"));
+    assert_eq!(
+        errors.0[0].to_string(),
+        "username -> Username must be between 3 and 32 characters"
+    );
+
+    assert_eq!(errors.0[1].code, Some("range"));
+    assert_eq!(
+        errors.0[1].message,
+        Some("You must be at least 13 years old")
+    );
+}
+
+#[test]
+fn code_without_message() {
+    let signup = Signup {
+        username: "admin".into(),
+        age: 30,
+    };
+    let err = signup.check().unwrap_err();
+    assert_eq!(err.code, Some("username_reserved"));
+    assert_eq!(err.to_string(), "username -> Not: expected \"admin\"");
+}
+
+#[derive(Check)]
+struct Tag {
+    #[check(Not("draft"), message = "{field} must not be {expected}")]
+    name: &'static str,
+    #[check(Range(1..=5), message = "{value} is not between {min} and {max}, {unknown}")]
+    priority: u8,
+    #[check(Email, message = "{value} is not a valid email")]
+    owner: &'static str,
+}
+
+#[test]
+fn message_templates() {
+    let tag = Tag {
+        name: "draft",
+        priority: 9,
+        owner: "nope",
+    };
+    let errors = tag.check_all().unwrap_err();
+    assert_eq!(errors.0[0].to_string(), "name -> name must not be draft");
+    assert_eq!(
+        errors.0[1].to_string(),
+        "priority -> 9 is not between 1 and 5, {unknown}"
+    );
+    assert_eq!(
+        errors.0[2].to_string(),
+        "owner -> nope is not a valid email"
+    );
+}
+
+fn is_even(n: &u32) -> bool {
+    n.is_multiple_of(2)
+}
+
+#[derive(Check)]
+struct Ticket {
+    #[check(Any((Pred(is_even, "{value} is odd"), Range(100..))))]
+    id: u32,
+    #[check(
+        Pred(|s: &String| s.contains('-'), "missing `-`"),
+        Not(Pred(|s: &String| s.ends_with('-'), "{value} ends with `-`")),
+    )]
+    code: String,
+}
+
+#[test]
+fn predicates() {
+    let ticket = Ticket {
+        id: 4,
+        code: "a-b".into(),
+    };
+    assert!(ticket.check().is_ok());
+
+    let odd = Ticket { id: 3, ..ticket };
+    let err = odd.check().unwrap_err();
+    assert_eq!(err.code, Some("any"));
+    assert_eq!(err.report().children[0].message, "3 is odd");
+
+    let code = Ticket {
+        id: 4,
+        code: "ab".into(),
+    };
+    let err = code.check().unwrap_err();
+    assert_eq!(err.code, Some("predicate"));
+    assert_eq!(err.to_string(), "code -> missing `-`");
+
+    let trailing = Ticket {
+        id: 4,
+        code: "ab-".into(),
+    };
+    assert_eq!(trailing.check().unwrap_err().code, Some("not"));
+}
+
+#[derive(Debug)]
+struct Reserved;
+
+impl std::fmt::Display for Reserved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("reserved name")
+    }
+}
+
+impl std::error::Error for Reserved {}
+
+fn not_reserved(name: &impl AsRef<str>) -> Result<(), Reserved> {
+    match name.as_ref() {
+        "root" => Err(Reserved),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Check)]
+struct Account {
+    #[check(not_reserved, message = "{field} is a {value}")]
+    name: String,
+    #[check(Length(3..32), message = "{field} must be at most {max} characters")]
+    bio: String,
+    #[check(Any((Checked(not_reserved), Length(8..))))]
+    nick: String,
+}
+
+#[test]
+fn errors_without_params() {
+    let account = Account {
+        name: "root".into(),
+        bio: "hello".into(),
+        nick: "rootkit".into(),
+    };
+    let err = account.check().unwrap_err();
+    assert_eq!(err.code, None);
+    assert_eq!(err.to_string(), "name -> name is a {value}");
+    assert!(err.report().params.is_empty());
+
+    let nick = Account {
+        name: "ferris".into(),
+        bio: "hello".into(),
+        nick: "root".into(),
+    };
+    let report = nick.check().unwrap_err().report();
+    assert_eq!(report.code, Some("any"));
+    assert_eq!(report.children[0].message, "reserved name");
+}
+
+#[test]
+fn exclusive_length_bounds() {
+    let account = Account {
+        name: "ferris".into(),
+        bio: "x".repeat(32),
+        nick: "ferris".into(),
+    };
+    let err = account.check().unwrap_err();
+    assert_eq!(err.to_string(), "bio -> bio must be at most 31 characters");
+
+    let err = Range(1..10).check(&10).unwrap_err();
+    let report = validex::errors::Report::new(&err);
+    assert_eq!(report.params["max"], errors::ParamValue::Int(10));
+    assert_eq!(
+        report.params["max_inclusive"],
+        errors::ParamValue::Bool(false)
+    );
+    assert_eq!(
+        report.params["min_inclusive"],
+        errors::ParamValue::Bool(true)
+    );
+}
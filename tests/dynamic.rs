@@ -0,0 +1,57 @@
+use validex::errors::Params;
+use validex::*;
+
+fn ports() -> Vec<DynRule<'static, u16>> {
+    vec![
+        DynRule::new(80),
+        DynRule::new(443),
+        DynRule::new(Range(8000..=8999)),
+    ]
+}
+
+#[derive(Check)]
+struct Server {
+    #[check(Any(ports()))]
+    port: u16,
+}
+
+#[test]
+fn passes() {
+    assert!(Server { port: 8080 }.check().is_ok());
+    assert!(All(Vec::<u8>::new()).verify(&1));
+    assert!(!Any(Vec::<u8>::new()).verify(&1));
+}
+
+#[test]
+fn empty_any_fails() {
+    let err = Any(Vec::<u8>::new()).check(&1).unwrap_err();
+    assert!(err.0.is_empty());
+    assert_eq!(err.to_string(), "no rules to satisfy");
+}
+
+#[test]
+fn any_collects_every_failure() {
+    let err = Server { port: 22 }.check().unwrap_err();
+    assert_eq!(err.code, Some("any"));
+    assert_eq!(err.report().children.len(), 3);
+}
+
+#[test]
+fn all_reports_first_failure() {
+    let name = All([Length(3..=32), Length(0..=16)]);
+    assert!(name.verify("web"));
+    let err = name.check("a-rather-long-name").unwrap_err();
+    assert_eq!(err.code(), Some("length"));
+    assert_eq!(err.to_string(), "expected length 18 in 0..=16");
+
+    let user = Not(Any(["root", "admin"].as_slice()));
+    assert!(user.check(&"www").is_ok());
+    assert_eq!(user.check(&"root").unwrap_err().code(), Some("not"));
+
+    let err = Not(All([Range(1..=9), Range(0..=5)]))
+        .check(&3)
+        .unwrap_err();
+    assert!(matches!(err.0, errors::CheckedError::Passed("all")));
+    let err = Not(All(Vec::<u8>::new())).check(&1).unwrap_err();
+    assert!(matches!(err.0, errors::CheckedError::Passed("all")));
+}
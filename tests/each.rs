@@ -0,0 +1,111 @@
+mod common;
+
+use common::failing_path;
+use std::collections::{BTreeSet, VecDeque};
+use validex::errors::CheckedError::*;
+use validex::*;
+
+#[derive(Check)]
+struct Tag {
+    #[check(Length(1..=8))]
+    name: String,
+}
+
+fn tag(name: &str) -> Tag {
+    Tag { name: name.into() }
+}
+
+#[derive(Check)]
+struct Post {
+    #[check(Each(Length(1..=8)))]
+    tags: Vec<String>,
+    #[check(Each(Valid))]
+    labels: Vec<Tag>,
+}
+
+#[derive(Check)]
+struct History {
+    #[check(Maybe(Each(Range(1..))))]
+    history: Option<VecDeque<u32>>,
+}
+
+#[derive(Check)]
+struct Scores {
+    #[check(EachAll(Range(..100)))]
+    scores: Vec<u32>,
+}
+
+#[test]
+fn valid() {
+    let post = Post {
+        tags: vec!["rust".into()],
+        labels: vec![tag("news")],
+    };
+    assert!(post.check().is_ok());
+    assert!(History { history: None }.check().is_ok());
+    assert!(Each(Range(1..=5)).verify(&[1, 3, 5]));
+    assert!(Each(Length(1..)).verify(&BTreeSet::from(["alice"])));
+}
+
+#[test]
+fn failing_index() {
+    let post = Post {
+        tags: vec!["rust".into(), "validation".into()],
+        labels: vec![],
+    };
+    let err = post.check().unwrap_err();
+    assert_eq!(err.path.to_string(), "tags[1]");
+    assert_eq!(err.code, Some("length"));
+    assert_eq!(err.render("{field} #{index}"), "tags[1] #1");
+
+    let history = History {
+        history: Some(VecDeque::from([1, 0])),
+    };
+    assert_eq!(failing_path(&history), "history[1]");
+
+    assert_eq!(Each(Range(1..=5)).check(&[1, 6, 0]).unwrap_err().index, 1);
+    let authors = BTreeSet::from(["", "bob"]);
+    assert_eq!(Each(Length(1..)).check(&authors).unwrap_err().index, 0);
+}
+
+#[test]
+fn nested() {
+    let post = Post {
+        tags: vec![],
+        labels: vec![tag("news"), tag("")],
+    };
+    let err = post.check().unwrap_err();
+    assert_eq!(err.path.to_string(), "labels[1].name");
+    assert_eq!(err.code, Some("length"));
+    assert_eq!(
+        err.to_string(),
+        "labels[1].name -> expected length 0 in 1..=8"
+    );
+}
+
+#[test]
+fn all_indices() {
+    let scores = Scores {
+        scores: vec![100, 5, 200],
+    };
+    let err = scores.check().unwrap_err();
+    assert_eq!(err.path.to_string(), "scores");
+    assert_eq!(err.code, Some("each"));
+    let report = err.report();
+    let paths: Vec<_> = report.children.iter().map(|c| c.path.as_str()).collect();
+    assert_eq!(paths, ["/0", "/2"]);
+}
+
+#[test]
+fn verify() {
+    assert!(Each(Range(1..=3)).verify(&[1, 2, 3]));
+    assert!(Not(Each(Range(1..=3))).verify(&vec![1, 4]));
+    let Failed(err) = Each(Range(1..=3)).error(&[1, 4, 5]) else {
+        panic!("expected a failed element");
+    };
+    assert_eq!(err.index, 1);
+
+    let err = Not(Each(Range(1..=3))).check(&[1, 2]).unwrap_err();
+    assert!(matches!(err.0, Passed("each")));
+    assert!(Not(Each(Range(1..=3))).check(&[]).is_err());
+}
@@ -0,0 +1,49 @@
+use validex::errors::Segment;
+use validex::*;
+
+#[derive(Check)]
+enum Payment {
+    Card {
+        #[check(Length(16..=16))]
+        number: String,
+        #[check(Range(100..=999))]
+        cvv: u16,
+    },
+    Iban(#[check(Length(15..=34))] String),
+    Cash,
+}
+
+#[test]
+fn struct_variant() {
+    let card = Payment::Card {
+        number: "4111111111111111".into(),
+        cvv: 42,
+    };
+    let err = card.check().unwrap_err();
+    assert_eq!(
+        err.path.0,
+        [Segment::Variant("Card"), Segment::Field("cvv")]
+    );
+    assert_eq!(err.to_string(), "Card.cvv -> expected 42 in 100..=999");
+}
+
+#[test]
+fn tuple_variant() {
+    let iban = Payment::Iban("DE89".into());
+    let err = iban.check().unwrap_err();
+    assert_eq!(err.path.0, [Segment::Variant("Iban"), Segment::Position(0)]);
+
+    assert!(Payment::Iban("DE89370400440532013000".into()).check().is_ok());
+    assert!(Payment::Cash.check().is_ok());
+}
+
+#[test]
+fn check_all_variant() {
+    let card = Payment::Card {
+        number: "4111".into(),
+        cvv: 42,
+    };
+    let errors = card.check_all().unwrap_err();
+    let paths: Vec<_> = errors.0.iter().map(|err| err.path.to_string()).collect();
+    assert_eq!(paths, ["Card.number", "Card.cvv"]);
+}
@@ -34,7 +34,7 @@ struct UserData {
 
 #[derive(Check)]
 struct User {
-    #[check(UserData::check)]
+    #[check(nested)]
     data: UserData,
 }
 
@@ -0,0 +1,93 @@
+use validex::i18n::{Catalog, Translate};
+use validex::*;
+
+#[derive(Check)]
+struct Profile {
+    #[check(Length(3..=32))]
+    name: String,
+    #[check(Range(13..), code = "age_minimum", message = "{field} must be at least {min}")]
+    age: u32,
+    #[check(Not("admin"))]
+    role: &'static str,
+}
+
+#[derive(Check)]
+struct Nickname {
+    #[check(AnyOf((Length(0..=0), Length(3..=16))))]
+    nickname: String,
+}
+
+/// Loads the German catalog from a file unique to the process and `test`,
+/// so concurrent test runs do not overwrite each other.
+fn catalog(test: &str) -> Catalog {
+    let name = format!("validex-i18n-{}-{test}-de.txt", std::process::id());
+    let path = std::env::temp_dir().join(name);
+    std::fs::write(
+        &path,
+        "# German\n\
+         length = {field} muss zwischen {min} und {max} Zeichen lang sein\n\
+         \n\
+         age_minimum = {field} muss mindestens {min} sein\n\
+         any = {field} erfüllt keine der Regeln\n",
+    )
+    .unwrap();
+    let mut catalog = Catalog::new();
+    catalog.load("de", &path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    catalog
+}
+
+#[test]
+fn translate_tree() {
+    let profile = Profile {
+        name: "al".into(),
+        age: 12,
+        role: "admin",
+    };
+    let errors = profile.check_all().unwrap_err();
+    let messages: Vec<_> = errors
+        .translate(&catalog("translate_tree"), "de")
+        .into_iter()
+        .map(|(path, message)| format!("{path}: {message}"))
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            "name: name muss zwischen 3 und 32 Zeichen lang sein",
+            "age: age muss mindestens 13 sein",
+            "role: Not: expected \"admin\"",
+        ]
+    );
+    assert_eq!(errors.0[2].code, Some("not"));
+
+    let english = errors.translate(&catalog("translate_tree"), "en");
+    assert_eq!(english[1].1, "age must be at least 13");
+}
+
+#[test]
+fn translate_nested() {
+    let nickname = Nickname {
+        nickname: "al".into(),
+    };
+    let err = nickname.check().unwrap_err();
+    let report = err.translate_report(&catalog("translate_nested"), "de");
+    assert_eq!(report.message, "nickname erfüllt keine der Regeln");
+    let messages: Vec<_> = report.children.iter().map(|c| c.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        [
+            "nickname muss zwischen 0 und 0 Zeichen lang sein",
+            "nickname muss zwischen 3 und 16 Zeichen lang sein",
+        ]
+    );
+
+    let english = err.translate_report(&catalog("translate_nested"), "en");
+    assert_eq!(english.children[1].message, "expected length 2 in 3..=16");
+}
+
+#[test]
+fn invalid_catalog() {
+    let mut catalog = Catalog::new();
+    assert!(catalog.parse("de", "length").is_err());
+    assert_eq!(catalog.template("de", "length"), None);
+}
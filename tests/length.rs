@@ -0,0 +1,69 @@
+use std::borrow::Cow;
+use std::collections::{BinaryHeap, LinkedList};
+use std::ffi::OsStr;
+use std::path::PathBuf;
+use validex::*;
+
+struct Playlist(Vec<&'static str>);
+
+impl Count for Playlist {
+    fn count(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[test]
+fn std_types() {
+    assert!(Length(..=2).check("abc").is_err());
+    assert!(Length(..=2).check(OsStr::new("ab")).is_ok());
+
+    let name: Cow<str> = Cow::Owned("a long name".into());
+    assert!(Length(1..=8).check(&name).is_err());
+    assert!(Length(1..=8).check(&Cow::Borrowed("song")).is_ok());
+
+    assert!(Length(..=16).check(&PathBuf::from("/tmp/song.mp3")).is_ok());
+    let path = PathBuf::from("/home/user/music/song.mp3");
+    assert!(Length(..=16).check(&path).is_err());
+
+    assert!(Length(1..).verify(&BinaryHeap::from([1])));
+    assert!(Length(..=2).verify(&LinkedList::<u32>::new()));
+}
+
+#[test]
+fn custom_count() {
+    assert!(Length(1..).verify(&Playlist(vec!["intro"])));
+    let err = Length(1..).check(&Playlist(vec![])).unwrap_err();
+    assert_eq!(err.to_string(), "expected length 0 in 1..");
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn smallvec() {
+    let items: smallvec::SmallVec<[u8; 4]> = smallvec::smallvec![1, 2, 3];
+    assert!(Length(..=2).check(&items).is_err());
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn indexmap() {
+    let map = indexmap::IndexMap::from([("a", 1), ("b", 2)]);
+    assert!(Length(2..=2).verify(&map));
+    let set = indexmap::IndexSet::from([1]);
+    assert!(Length(1..).check(&set).is_ok());
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn arrayvec() {
+    let items: arrayvec::ArrayVec<u8, 4> = [1, 2].into_iter().collect();
+    assert!(Length(3..).check(&items).is_err());
+    let name = arrayvec::ArrayString::<8>::from("abc").unwrap();
+    assert!(Length(..=3).check(&name).is_ok());
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn bytes() {
+    let body = bytes::Bytes::from_static(b"hello");
+    assert!(Length(..=4).check(&body).is_err());
+}
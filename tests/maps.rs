@@ -0,0 +1,113 @@
+mod common;
+
+use common::failing_path;
+use std::collections::{BTreeMap, HashMap};
+use validex::*;
+
+#[derive(Check)]
+struct Endpoint {
+    #[check(Range(1..=65535))]
+    port: u32,
+}
+
+#[derive(Check)]
+struct Services {
+    #[check(Keys(is_slug), Values(Valid))]
+    endpoints: BTreeMap<String, Endpoint>,
+}
+
+#[derive(Check)]
+struct Limits {
+    #[check(Values(Range(..=100)))]
+    limits: BTreeMap<String, u32>,
+}
+
+#[derive(Check)]
+struct Aliases {
+    #[check(Entries(prefixed))]
+    aliases: BTreeMap<String, String>,
+}
+
+fn is_slug(key: &impl AsRef<str>) -> Result<(), &'static str> {
+    let key = key.as_ref();
+    if key.is_empty() || !key.bytes().all(|b| b.is_ascii_lowercase() || b == b'-') {
+        return Err("must be a slug");
+    }
+    Ok(())
+}
+
+fn prefixed((key, val): (&String, &String)) -> Result<(), &'static str> {
+    if !val.starts_with(key.as_str()) {
+        return Err("alias must start with its key");
+    }
+    Ok(())
+}
+
+fn endpoints<const N: usize>(ports: [(&str, u32); N]) -> Services {
+    let endpoints = ports.map(|(key, port)| (key.into(), Endpoint { port }));
+    Services {
+        endpoints: BTreeMap::from(endpoints),
+    }
+}
+
+#[test]
+fn valid() {
+    assert!(endpoints([("api", 8080)]).check().is_ok());
+    let limits = Limits {
+        limits: BTreeMap::from([("requests".into(), 50)]),
+    };
+    assert!(limits.check().is_ok());
+    let aliases = Aliases {
+        aliases: BTreeMap::from([("db".into(), "db-primary".into())]),
+    };
+    assert!(aliases.check().is_ok());
+}
+
+#[test]
+fn keys() {
+    let services = endpoints([("Api", 8080), ("api", 8080), ("Web", 80)]);
+    let err = services.check().unwrap_err();
+    assert_eq!(err.path.to_string(), r#"endpoints["Api"]"#);
+    assert_eq!(err.to_string(), r#"endpoints["Api"] -> must be a slug"#);
+    assert_eq!(err.path.to_pointer(), "/endpoints/Api");
+}
+
+#[test]
+fn values() {
+    let services = endpoints([("api", 0), ("web", 0)]);
+    let err = services.check().unwrap_err();
+    assert_eq!(err.path.to_string(), r#"endpoints["api"].port"#);
+    assert_eq!(err.error.to_string(), "expected 0 in 1..=65535");
+
+    let limits = Limits {
+        limits: BTreeMap::from([("requests".into(), 500)]),
+    };
+    let err = limits.check().unwrap_err();
+    assert_eq!(err.path.to_string(), r#"limits["requests"]"#);
+    assert_eq!(err.render("{key} is {value}"), "requests is 500");
+}
+
+#[test]
+fn entries() {
+    let aliases = Aliases {
+        aliases: BTreeMap::from([("db".into(), "primary".into())]),
+    };
+    assert_eq!(failing_path(&aliases), r#"aliases["db"]"#);
+}
+
+#[test]
+fn verify() {
+    let limits = BTreeMap::from([("a", 1), ("b", 200)]);
+    assert!(Keys(Not("c")).verify(&limits));
+    assert!(!Values(Range(..=100)).verify(&limits));
+    match Values(Range(..=100)).error(&limits) {
+        errors::CheckedError::Failed(err) => assert_eq!(err.key, &"b"),
+        errors::CheckedError::Passed(_) => panic!("expected a failed entry"),
+    }
+    assert!(Not(Keys(Not("c"))).check(&limits).is_err());
+
+    // The verdict does not depend on the iteration order of a `HashMap`.
+    let limits = HashMap::from([("a", 1), ("b", 200)]);
+    assert!(Keys(Not("c")).verify(&limits));
+    assert!(!Values(Range(..=100)).verify(&limits));
+}
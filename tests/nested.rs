@@ -0,0 +1,70 @@
+mod common;
+
+use common::{Address, address};
+use std::collections::BTreeMap;
+use validex::*;
+
+#[derive(Check)]
+struct User {
+    #[check(Range(1..))]
+    id: u32,
+    #[check(nested)]
+    address: Address,
+    #[check(nested)]
+    billing: Option<Address>,
+    #[check(nested)]
+    previous: Vec<Address>,
+    #[check(nested)]
+    offices: BTreeMap<String, Address>,
+}
+
+#[test]
+fn valid() {
+    let user = User {
+        id: 1,
+        address: address("Dhaka"),
+        billing: None,
+        previous: vec![address("Sylhet")],
+        offices: BTreeMap::from([("hq".into(), address("Berlin"))]),
+    };
+    assert!(user.check().is_ok());
+    assert!(user.check_all().is_ok());
+}
+
+#[test]
+fn field_path() {
+    let user = User {
+        id: 1,
+        address: address(""),
+        billing: None,
+        previous: vec![],
+        offices: BTreeMap::new(),
+    };
+    let err = user.check().unwrap_err();
+    assert_eq!(err.path.to_string(), "address.city");
+    assert_eq!(err.path.to_pointer(), "/address/city");
+    assert_eq!(err.to_string(), "address.city -> expected length 0 in 1..");
+}
+
+#[test]
+fn collection_paths() {
+    let user = User {
+        id: 0,
+        address: address("Dhaka"),
+        billing: Some(address("")),
+        previous: vec![address("Sylhet"), address("")],
+        offices: BTreeMap::from([("eu/west".into(), address(""))]),
+    };
+    let errors = user.check_all().unwrap_err();
+    let paths: Vec<_> = errors.0.iter().map(|err| err.path.to_pointer()).collect();
+    assert_eq!(
+        paths,
+        [
+            "/id",
+            "/billing/city",
+            "/previous/1/city",
+            "/offices/eu~1west/city"
+        ]
+    );
+    assert_eq!(errors.0[2].path.to_string(), "previous[1].city");
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "regex")]
+
+use validex::errors::Params;
+use validex::*;
+
+#[derive(Check)]
+struct Article {
+    #[check(Pattern!("^[a-z0-9-]+$"), Not(Pattern!("--")))]
+    slug: String,
+}
+
+#[test]
+fn matches() {
+    let slug = Article {
+        slug: "hello-world".into(),
+    };
+    assert!(slug.check().is_ok());
+
+    let slug = Article {
+        slug: "Hello World".into(),
+    };
+    let err = slug.check().unwrap_err();
+    assert_eq!(err.code, Some("pattern"));
+    assert_eq!(
+        err.render("{value} !~ {pattern}"),
+        "Hello World !~ ^[a-z0-9-]+$"
+    );
+
+    let dashes = Article {
+        slug: "hello--world".into(),
+    };
+    assert_eq!(dashes.check().unwrap_err().code, Some("not"));
+
+    let year = Maybe(Any((Pattern!(r"^\d{4}$"), Pattern!(r"^\d{4}-\d{2}$"))));
+    assert!(year.check(&Some("2024-05")).is_ok());
+    assert_eq!(year.check(&Some("24")).unwrap_err().code(), Some("any"));
+}
+
+#[test]
+fn cached() {
+    fn slug() -> Pattern {
+        Pattern!("^[a-z]+$")
+    }
+    assert!(std::ptr::eq(slug().regex(), slug().regex()));
+}
+
+#[test]
+fn runtime() {
+    let pattern = Pattern::from(regex::Regex::new("^a+$").unwrap());
+    assert!(pattern.verify("aaa"));
+    let err = pattern.error("b");
+    assert_eq!(err.code(), Some("pattern"));
+    assert_eq!(err.to_string(), r#""b" does not match `^a+$`"#);
+    assert_eq!(Pattern::new("^b$").as_str(), "^b$");
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "serde")]
+
+use validex::*;
+
+#[derive(Check)]
+struct Address {
+    #[check(Length(1..), code = "city_required")]
+    city: String,
+}
+
+#[derive(Check)]
+struct User {
+    #[check(AnyOf((Range(20..=30), Range(100..))))]
+    id: u32,
+    #[check(nested)]
+    address: Address,
+}
+
+#[test]
+fn serialize_report() {
+    let user = User {
+        id: 45,
+        address: Address { city: "".into() },
+    };
+    let errors = user.check_all().unwrap_err();
+    let json = serde_json::to_value(errors.report()).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!([
+            {
+                "path": "/id",
+                "kind": "any",
+                "code": "any",
+                "message": "expected 45 in 20..=30; expected 45 in 100..; ",
+                "params": {},
+                "children": [
+                    {
+                        "path": "",
+                        "kind": "range",
+                        "code": "range",
+                        "message": "expected 45 in 20..=30",
+                        "params": {
+                            "value": 45,
+                            "min": 20,
+                            "min_inclusive": true,
+                            "max": 30,
+                            "max_inclusive": true,
+                        },
+                        "children": [],
+                    },
+                    {
+                        "path": "",
+                        "kind": "range",
+                        "code": "range",
+                        "message": "expected 45 in 100..",
+                        "params": { "value": 45, "min": 100, "min_inclusive": true },
+                        "children": [],
+                    },
+                ],
+            },
+            {
+                "path": "/address/city",
+                "kind": "length",
+                "code": "city_required",
+                "message": "expected length 0 in 1..",
+                "params": { "len": 0, "min": 1, "unit": "len" },
+                "children": [],
+            },
+        ])
+    );
+}
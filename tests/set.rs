@@ -0,0 +1,78 @@
+use std::collections::{BTreeSet, HashSet};
+use validex::*;
+
+#[derive(Check)]
+struct Signup {
+    #[check(NotIn(["admin", "root"]))]
+    username: String,
+}
+
+#[derive(Check)]
+struct Address {
+    #[check(In(COUNTRIES))]
+    country: String,
+}
+
+#[derive(Check)]
+struct Subscription {
+    #[check(Maybe(In(BTreeSet::from([1, 2, 3]))))]
+    plan: Option<u32>,
+}
+
+const COUNTRIES: [&str; 12] = [
+    "at", "be", "bd", "de", "dk", "es", "fr", "it", "jp", "nl", "se", "us",
+];
+
+#[test]
+fn in_set() {
+    let address = Address {
+        country: "bd".into(),
+    };
+    assert!(address.check().is_ok());
+
+    let address = Address {
+        country: "xx".into(),
+    };
+    let err = address.check().unwrap_err();
+    assert_eq!(err.code, Some("in"));
+    assert_eq!(
+        err.to_string(),
+        r#"country -> expected one of ["at", "be", "bd", "de", "dk", "es", "fr", "it", "jp", "nl", … 2 more]"#
+    );
+
+    assert!(Subscription { plan: None }.check().is_ok());
+    assert!(Subscription { plan: Some(2) }.check().is_ok());
+    let plan = Subscription { plan: Some(4) };
+    assert_eq!(
+        plan.check().unwrap_err().render("{value} not in {set}"),
+        "4 not in [1, 2, 3]"
+    );
+}
+
+#[test]
+fn not_in_set() {
+    let signup = Signup {
+        username: "ferris".into(),
+    };
+    assert!(signup.check().is_ok());
+
+    let signup = Signup {
+        username: "root".into(),
+    };
+    let err = signup.check().unwrap_err();
+    assert_eq!(err.code, Some("not_in"));
+    assert_eq!(err.to_string(), r#"username -> "root" is not allowed"#);
+}
+
+#[test]
+fn large_sets() {
+    let allowed: HashSet<u32> = (0..1000).map(|n| n * 2).collect();
+    assert!(In(&allowed).verify(&998));
+    assert!(!In(&allowed).verify(&999));
+    let sorted: Vec<u32> = (0..1000).collect();
+    assert!(In(Sorted(sorted)).verify(&500));
+    assert!(!In(Sorted(&COUNTRIES[..])).verify(&"xx"));
+    assert!(In(Sorted(COUNTRIES)).verify(&"se"));
+    let names: HashSet<String> = HashSet::from(["ferris".into()]);
+    assert!(NotIn(names).check(&"crab".to_string()).is_ok());
+}
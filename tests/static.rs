@@ -0,0 +1,116 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use validex::errors::Either;
+use validex::*;
+
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+fn allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[derive(Check)]
+#[check(error = OrderError)]
+struct Order {
+    #[check(AllOf((Range(1..=100), Not(13))))]
+    quantity: u32,
+    #[check(AnyOf((Length(0..=0), AllOf((Length(3..=8), Ascii)))))]
+    coupon: String,
+}
+
+fn order(quantity: u32, coupon: &str) -> Order {
+    Order {
+        quantity,
+        coupon: coupon.into(),
+    }
+}
+
+#[test]
+fn does_not_allocate() {
+    let order = order(13, "välkommen");
+    let rule = AllOf((Range(1..=100), Not(13)));
+    let (result, count) = allocations(|| rule.check(&order.quantity).is_err());
+    assert!(result);
+    assert_eq!(count, 0);
+
+    let rule = AnyOf((Length(0..=0), AllOf((Length(3..=8), Ascii))));
+    let (err, count) = allocations(|| rule.check(&order.coupon).unwrap_err());
+    assert_eq!(count, 0);
+    assert!(matches!(err.0.1, Either::Left(_)));
+
+    let (_, count) = allocations(|| Any((Length(0..=0), Ascii)).check(&order.coupon));
+    assert!(count > 0);
+}
+
+#[test]
+fn field_errors() {
+    assert!(order(2, "").check().is_ok());
+
+    let unlucky = order(13, "");
+    assert_eq!(unlucky.check().unwrap_err().code, Some("not"));
+
+    let coupon = order(2, "välkommen");
+    let err = coupon.check().unwrap_err();
+    assert_eq!(err.code, Some("any"));
+    let codes: Vec<_> = err.report().children.iter().map(|c| c.code).collect();
+    assert_eq!(codes, [Some("length"), Some("length")]);
+}
+
+#[test]
+fn typed_errors_do_not_allocate() {
+    let coupon = order(2, "välkommen");
+    let (err, count) = allocations(|| coupon.check_typed().unwrap_err());
+    assert_eq!(count, 0);
+    assert!(matches!(err, OrderError::Coupon(_)));
+}
+
+#[derive(Check)]
+struct Gift {
+    #[check(Maybe(Valid))]
+    order: Option<Order>,
+}
+
+fn no_coupon(order: &Order) -> Result<(), &'static str> {
+    match order.coupon.is_empty() {
+        true => Ok(()),
+        false => Err("coupon given"),
+    }
+}
+
+#[test]
+fn any_of_check_rules() {
+    let rule = AnyOf((Valid, no_coupon));
+    assert!(rule.check(&order(2, "")).is_ok());
+
+    let order = order(0, "welcome");
+    let err = rule.check(&order).unwrap_err();
+    assert_eq!(err.0.1, "coupon given");
+    assert!(Gift { order: None }.check().is_ok());
+}
+
+#[test]
+fn counting_passes_does_not_allocate() {
+    let rule = AtLeast(1, (Range(10..), Range(1..=5), Not(3)));
+    let (result, count) = allocations(|| rule.check(&4));
+    assert!(result.is_ok());
+    assert_eq!(count, 0);
+}
@@ -0,0 +1,88 @@
+use validex::errors::Params;
+use validex::*;
+
+#[derive(Check)]
+struct Account {
+    #[check(NonBlank, Trimmed, Lowercase, Chars(|c: char| c.is_alphanumeric() || c == '_'))]
+    username: String,
+}
+
+#[test]
+fn valid() {
+    let account = Account {
+        username: "ferris_42".into(),
+    };
+    assert!(account.check().is_ok());
+    assert!(Maybe(Ascii).check(&Some("Fearless concurrency")).is_ok());
+    assert!(Maybe(NoControlChars).check(&None::<String>).is_ok());
+    assert!(Alphanumeric.verify("AB12") && Uppercase.verify("AB12"));
+}
+
+#[test]
+fn offending_char() {
+    let account = Account {
+        username: "fërris Crab".into(),
+    };
+    let err = account.check().unwrap_err();
+    assert_eq!(err.code, Some("lowercase"));
+    assert_eq!(
+        err.render("{char} at byte {offset}, char {index}"),
+        "C at byte 8, char 7"
+    );
+    assert_eq!(
+        err.to_string(),
+        "username -> unexpected character 'C' at offset 8"
+    );
+
+    let motto = Maybe(Ascii).check(&Some("naïve")).unwrap_err();
+    assert_eq!((motto.offset, motto.index), (2, 2));
+
+    let control = NoControlChars.check("line\nbreak").unwrap_err();
+    assert_eq!((control.char, control.offset), ('\n', 4));
+
+    assert_eq!(
+        Alphanumeric.check("AB-12").unwrap_err().code,
+        "alphanumeric"
+    );
+    assert!(!Uppercase.verify("ABc"));
+
+    let err = Not(Lowercase).check("abc").unwrap_err();
+    assert_eq!(err.to_string(), "Not: expected lowercase to fail");
+    assert!(Not(Lowercase).verify("aBc"));
+}
+
+#[test]
+fn blank_and_trimmed() {
+    let blank = Account {
+        username: "  ".into(),
+    };
+    assert_eq!(blank.check().unwrap_err().code, Some("blank"));
+    let leading = Trimmed.check(" ferris").unwrap_err();
+    assert_eq!(leading.offset, 0);
+    let trailing = Trimmed.check("ferris\u{3000}").unwrap_err();
+    assert_eq!((trailing.offset, trailing.index), (6, 6));
+    let trailing = Trimmed.check("ab   ").unwrap_err();
+    assert_eq!(trailing.offset, 2);
+    assert!(Trimmed.verify(""));
+}
+
+#[test]
+fn content() {
+    let repo = "https://example.com/crab.git";
+    assert!(StartsWith("https://").verify(repo) && EndsWith(".git").verify(repo));
+
+    let err = StartsWith("https://")
+        .check("http://example.com/crab.git")
+        .unwrap_err();
+    assert_eq!(err.code, "starts_with");
+    assert_eq!(
+        err.to_string(),
+        r#"expected "http://example.com/crab.git" to start with "https://""#
+    );
+
+    let err = EndsWith(".git").error("crab");
+    assert_eq!(err.code, "ends_with");
+
+    let dots = Not(Contains("..")).check("https://example.com/../crab.git");
+    assert_eq!(dots.unwrap_err().code(), Some("not"));
+}
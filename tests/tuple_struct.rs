@@ -0,0 +1,41 @@
+use validex::errors::Segment;
+use validex::*;
+
+#[derive(Check)]
+struct UserId(#[check(Range(1..))] u32);
+
+#[derive(Check)]
+#[check(Length(3..=32))]
+struct Username(String);
+
+#[derive(Check)]
+struct Point(
+    #[check(Range(-90.0..=90.0))] f64,
+    #[check(Range(-180.0..=180.0))] f64,
+);
+
+#[test]
+fn newtype() {
+    assert!(UserId(7).check().is_ok());
+    let id = UserId(0);
+    let err = id.check().unwrap_err();
+    assert_eq!(err.path.0, [Segment::Position(0)]);
+}
+
+#[test]
+fn container_rule_on_newtype() {
+    assert!(Username("alice".into()).check().is_ok());
+    let name = Username("al".into());
+    assert!(name.check().unwrap_err().path.0.is_empty());
+    assert_eq!(
+        name.check().unwrap_err().to_string(),
+        "expected length 2 in 3..=32"
+    );
+}
+
+#[test]
+fn positional_fields() {
+    assert!(Point(23.8, 90.4).check().is_ok());
+    let point = Point(23.8, 190.0);
+    assert_eq!(point.check().unwrap_err().path.0, [Segment::Position(1)]);
+}
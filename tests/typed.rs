@@ -0,0 +1,128 @@
+use validex::errors::Params;
+use validex::*;
+
+#[derive(Check)]
+#[check(error = SignupError, passwords_match)]
+pub struct Signup {
+    #[check(Length(3..=16), Not(Length(5..=5)))]
+    username: String,
+    #[check(Range(13..), code = "too_young")]
+    age: u8,
+    #[check(Length(8..))]
+    password: String,
+    password_confirm: String,
+}
+
+fn passwords_match(signup: &Signup) -> Result<(), &'static str> {
+    if signup.password != signup.password_confirm {
+        return Err("passwords do not match");
+    }
+    Ok(())
+}
+
+#[derive(Check)]
+#[check(error = ShapeError)]
+enum Shape {
+    Circle(#[check(Range(1..))] u32),
+    Rect {
+        #[check(Range(1..))]
+        width: u32,
+        #[check(Range(1..))]
+        height: u32,
+    },
+}
+
+fn signup(username: &str, age: u8) -> Signup {
+    Signup {
+        username: username.into(),
+        age,
+        password: "correct horse".into(),
+        password_confirm: "correct horse".into(),
+    }
+}
+
+#[test]
+fn field_variants() {
+    assert!(signup("alicealice", 30).check_typed().is_ok());
+
+    let short = signup("al", 30);
+    let err = short.check_typed().unwrap_err();
+    assert!(matches!(err, SignupError::Username(_)));
+    assert_eq!(err.code(), Some("length"));
+
+    let five = signup("alice", 30);
+    let err = five.check_typed().unwrap_err();
+    assert!(matches!(err, SignupError::Username(_)));
+    assert_eq!(err.code(), Some("not"));
+
+    let young = signup("alicealice", 12);
+    let err = young.check_typed().unwrap_err();
+    assert!(matches!(err, SignupError::Age(_)));
+    assert_eq!(err.code(), Some("too_young"));
+    assert!(err.to_string().starts_with("age -> "));
+}
+
+#[test]
+fn container_variant() {
+    let mismatch = Signup {
+        username: "alicealice".into(),
+        age: 30,
+        password: "correct horse".into(),
+        password_confirm: "battery staple".into(),
+    };
+    let err = mismatch.check_typed().unwrap_err();
+    assert!(matches!(err, SignupError::Signup(_)));
+    assert_eq!(err.to_string(), "passwords do not match");
+}
+
+#[test]
+fn enum_variants() {
+    assert!(Shape::Circle(1).check_typed().is_ok());
+    let err = Shape::Circle(0).check_typed().unwrap_err();
+    assert!(matches!(err, ShapeError::Circle0(_)));
+    assert!(err.to_string().starts_with("Circle.0 -> "));
+
+    let err = Shape::Rect {
+        width: 0,
+        height: 0,
+    }
+    .check_typed()
+    .unwrap_err();
+    assert!(matches!(err, ShapeError::RectWidth(_)));
+
+    let err = Shape::Rect {
+        width: 1,
+        height: 0,
+    }
+    .check_typed()
+    .unwrap_err();
+    assert!(matches!(err, ShapeError::RectHeight(_)));
+    assert!(err.to_string().starts_with("Rect.height -> "));
+}
+
+fn typed_code<T: ValidateTyped>(val: &T) -> Option<&'static str> {
+    val.check_typed().err()?.code()
+}
+
+#[test]
+fn generic_over_typed() {
+    assert_eq!(typed_code(&signup("alicealice", 30)), None);
+    assert_eq!(typed_code(&Shape::Circle(0)), Some("range"));
+}
+
+#[derive(Check)]
+#[check(error = NicknameError, Length(3..=16))]
+struct Nickname(#[check(Not(Length(5..=5)))] String);
+
+#[test]
+fn newtype_container_variant() {
+    let short = Nickname("al".into());
+    let err = short.check_typed().unwrap_err();
+    assert!(matches!(err, NicknameError::Nickname(_)));
+    assert_eq!(err.to_string(), "expected length 2 in 3..=16");
+
+    let five = Nickname("alice".into());
+    let err = five.check_typed().unwrap_err();
+    assert!(matches!(err, NicknameError::Field0(_)));
+    assert!(err.to_string().starts_with("0 -> "));
+}
@@ -0,0 +1,65 @@
+use validex::errors::LengthUnit;
+use validex::*;
+
+#[derive(Check)]
+struct Profile {
+    #[check(CharLength(1..=10))]
+    name: String,
+    #[check(Utf16Length(..=4))]
+    bio: String,
+}
+
+#[test]
+fn chars() {
+    let profile = Profile {
+        name: "অনুপম রায়".into(),
+        bio: "hi".into(),
+    };
+    assert!(profile.name.len() > 10);
+    assert!(profile.check().is_ok());
+
+    let long = Profile {
+        name: "a".repeat(11),
+        ..profile
+    };
+    let err = long.check().unwrap_err();
+    assert_eq!(err.path.to_string(), "name");
+    assert_eq!(
+        err.render("{len} {unit}, at most {max}"),
+        "11 chars, at most 10"
+    );
+    assert_eq!(
+        err.to_string(),
+        "name -> expected length 11 chars in 1..=10"
+    );
+}
+
+#[test]
+fn utf16() {
+    let profile = Profile {
+        name: "crab".into(),
+        bio: "🦀🦀🦀".into(),
+    };
+    let err = profile.check().unwrap_err();
+    assert_eq!(err.path.to_string(), "bio");
+    assert_eq!(err.render("{len} {unit}"), "6 utf16");
+}
+
+#[test]
+fn unit() {
+    let err = Length(..=1).error("ab");
+    assert_eq!(err.unit, LengthUnit::Len);
+    let err = CharLength(..=1).check("ab").unwrap_err();
+    assert_eq!(err.unit, LengthUnit::Chars);
+    assert!(Not(CharLength(..=1)).verify("ab"));
+}
+
+#[cfg(feature = "graphemes")]
+#[test]
+fn graphemes() {
+    let family = "👨‍👩‍👧";
+    assert!(CharLength(..=1).check(family).is_err());
+    let err = GraphemeLength(2..).check(family).unwrap_err();
+    assert_eq!(err.len, 1);
+    assert_eq!(err.unit, LengthUnit::Graphemes);
+}
@@ -0,0 +1,58 @@
+use std::cell::Cell;
+use std::ops::RangeTo;
+use validex::*;
+
+/// Fails every value, counting how many times it was evaluated.
+#[derive(Clone, Copy)]
+struct Counting<'c>(&'c Cell<usize>);
+
+impl Counting<'_> {
+    fn evaluate<'a>(&self, value: &'a u32) -> errors::RangeError<&'a u32, RangeTo<u32>> {
+        self.0.set(self.0.get() + 1);
+        errors::RangeError { value, range: ..0 }
+    }
+}
+
+impl<'a> Verify<&'a u32> for Counting<'_> {
+    type Error = errors::RangeError<&'a u32, RangeTo<u32>>;
+    fn verify(&self, val: &'a u32) -> bool {
+        self.evaluate(val);
+        false
+    }
+    fn error(&self, val: &'a u32) -> Self::Error {
+        self.evaluate(val)
+    }
+    fn try_verify(&self, val: &'a u32) -> Result<(), Self::Error> {
+        Err(self.evaluate(val))
+    }
+}
+
+#[test]
+fn evaluates_failing_rules_once() {
+    let count = Cell::new(0);
+    let rule = Counting(&count);
+    assert!(All((Range(1..), rule)).check(&5u32).is_err());
+    assert_eq!(count.take(), 1);
+
+    assert!(Any((rule, rule, Range(..3))).check(&5u32).is_err());
+    assert_eq!(count.take(), 2);
+
+    assert!(AtLeast(1, (rule, Range(..3))).check(&5u32).is_err());
+    assert_eq!(count.take(), 1);
+
+    assert!(AtMost(0, [rule, rule]).check(&5u32).is_ok());
+    assert_eq!(count.take(), 2);
+
+    assert!(All([rule, rule]).check(&5u32).is_err());
+    assert_eq!(count.take(), 1);
+
+    assert!(Each(rule).try_verify(&vec![1u32, 2]).is_err());
+    assert_eq!(count.take(), 1);
+}
+
+#[test]
+fn stops_at_first_passing_rule() {
+    let count = Cell::new(0);
+    assert!(Any((Range(1..), Counting(&count))).check(&5u32).is_ok());
+    assert_eq!(count.take(), 0);
+}